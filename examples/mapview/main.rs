@@ -1,6 +1,5 @@
-use std::collections::HashMap;
 use std::fs::File;
-use std::io::BufWriter;
+use std::io::{BufWriter, Read, Seek, SeekFrom};
 
 use clap::Parser;
 use png;
@@ -8,34 +7,36 @@ use png;
 use cli::{Action, Options};
 use cli::export::elevation;
 use cli::export::filter::{Filter, Layers};
-use ycresources::common::types::geometry::Scaled;
-use ycresources::common::types::space;
 use ycresources::formats::{map, pal};
-use ycresources::formats::map::blueprint::prototype::Instance;
-use ycresources::formats::map::tiles::Group;
-use provider::CommonProvider;
+use ycresources::provider::{prototype, sprite, storage};
+use ycresources::common::types::version::Version;
+use ycresources::render;
 
 use crate::cli::export::darkness::Darkness;
 
 mod print;
-mod render;
-mod provider;
-mod traits;
 mod cli;
-mod error;
 
 fn main() {
     let options = Options::parse();
 
-    let directory = &options.resources.join("PROTO");
-    let provider = CommonProvider { directory: directory.as_path() };
-
     let map = match File::open(&options.input) {
         Err(error) => { return eprintln!("Couldn't open input file: {:?}.", error); }
         Ok(value) => value,
     };
 
     let mut reader = std::io::BufReader::with_capacity(1 * 1024 * 1024, map);
+
+    let mut version = [0u8; 4];
+    let version = match reader.read_exact(&mut version)
+        .and_then(|_| reader.seek(SeekFrom::Start(0)))
+        .map_err(|e| ycresources::common::types::errors::Error::IO(e))
+        .and_then(|_| Version::try_from(u32::from_be_bytes(version))) {
+        Err(error) => { return eprintln!("Couldn't determine map version: {:?}.", error); }
+        Ok(value) => value,
+    };
+
+    let provider = prototype::CachingProvider::new(storage::Directory { root: options.resources.clone() }, version);
     let map = match map::parse::map(&mut reader, &provider) {
         Err(error) => { return eprintln!("Couldn't parse map file: {:?}.", error); }
         Ok(value) => value,
@@ -55,8 +56,7 @@ fn main() {
             let filter = export.filter
                 .map_or(Layers::default(), |f| match f { Filter::Layers(layers) => layers });
 
-            let directory = &options.resources.join("ART");
-            let provider = CommonProvider { directory: directory.as_path() };
+            let provider = sprite::StorageProvider { storage: storage::Directory { root: options.resources.clone() } };
 
             const MAX_ELEVATION: u8 = 2;
             let levels = export.elevation.as_ref()
@@ -108,53 +108,38 @@ fn main() {
 
             println!("Success.");
 
-            let mut tiles = HashMap::new();
-            for group in &map.tiles { tiles.insert(&group.elevation, group); }
-
-            let mut protos: HashMap<&space::Elevation, Vec<&Instance>> = HashMap::new();
-            for proto in &map.prototypes {
-                if let Some(grid) = &proto.location.grid {
-                    if let Some(protos) = protos.get_mut(&grid.elevation) {
-                        protos.push(&proto);
-                    } else {
-                        let mut list = Vec::new();
-                        list.push(proto);
-
-                        protos.insert(&grid.elevation, list);
-                    }
+            let layers = if filter.all() {
+                render::Layers { overlay: true, ..render::Layers::default() }
+            } else {
+                render::Layers {
+                    floor: filter.floor,
+                    roof: filter.roof,
+                    walls: filter.walls,
+                    scenery: filter.scenery,
+                    items: filter.items,
+                    critters: filter.critters,
+                    misc: filter.misc,
+                    overlay: filter.overlay,
                 }
-            }
+            };
 
-            let drawables: Vec<(space::Elevation, &&Group, Option<&Vec<&Instance>>)> = levels.map(
-                |l| space::Elevation { level: Scaled { value: l, scale: 0..MAX_ELEVATION + 1 } }
-            ).map(|e| {
-                let protos = protos.get(&e);
-                tiles.get(&e).map(|t| (e, t, protos))
-            }).flatten().collect();
+            let options = render::Options { layers, darkness: Some(darkness), region: None };
 
-            for (elevation, tiles, protos) in drawables {
-                let level_readable = elevation.level.value + 1;
+            for level in levels {
+                let level_readable = level + 1;
 
-                println!("Started rendering level {:?}...", level_readable);
-                let result = render::map(
-                    &tiles,
-                    protos,
-                    &filter,
-                    darkness,
-                    &provider,
-                    &palette,
-                );
+                if !map.has_elevation(level) {
+                    println!("Elevation {:?} is not present in the file, skipping...", level_readable);
+                    continue;
+                }
 
-                let image = match result {
+                println!("Started rendering level {:?}...", level_readable);
+                let image = match render::elevation(&map, level, &provider, &palette, &options) {
                     Err(error) => {
-                        eprintln!("Failed to render elevation {:?}. Error: {:?}.", level_readable, error.to_string());
+                        eprintln!("Failed to render elevation {:?}. Error: {:?}.", level_readable, error);
                         continue;
                     }
-                    Ok(None) => {
-                        println!("Elevation {:?} is not present in the file, skipping...", level_readable);
-                        continue;
-                    }
-                    Ok(Some(value)) => {
+                    Ok(value) => {
                         println!("Succeeded rendering elevation {:?}.", level_readable);
                         value
                     }
@@ -173,7 +158,7 @@ fn main() {
 
                 println!("Writing header...");
                 let mut encoder =
-                    png::Encoder::new(writer, image.1.0 as u32, image.1.1 as u32);
+                    png::Encoder::new(writer, image.width as u32, image.height as u32);
 
                 encoder.set_color(png::ColorType::Rgba);
                 encoder.set_depth(png::BitDepth::Eight);
@@ -186,21 +171,8 @@ fn main() {
                     }
                 };
 
-                println!("Converting bitmap...");
-                let data: Vec<u8> = image.0
-                    .iter()
-                    .map(|t| { (t.0, t.1, t.2, u8::MAX) })
-                    .fold(Vec::new(), |mut accum, t| {
-                        accum.push(t.0);
-                        accum.push(t.1);
-                        accum.push(t.2);
-                        accum.push(t.3);
-
-                        accum
-                    });
-
                 println!("Writing image...");
-                if let Err(error) = writer.write_image_data(&data) {
+                if let Err(error) = writer.write_image_data(&image.pixels) {
                     eprintln!("Couldn't write PNG header: {:}.", error);
                     continue;
                 }
@@ -1 +0,0 @@
-pub(crate) mod render;
\ No newline at end of file
@@ -0,0 +1,103 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Cursor, Read, Write};
+
+use clap::Parser;
+
+use ycresources::common::types::version::Version;
+use ycresources::formats::pro;
+
+#[derive(Parser)]
+#[clap(name = "protoedit", version)]
+struct Options {
+    /// Path to the input file, either prototype (.pro) or its text form (.json)
+    #[clap(short, long)]
+    input: String,
+    /// Path to the output file
+    #[clap(short, long)]
+    output: String,
+    /// Treat the input as a Fallout 1 prototype
+    #[clap(long)]
+    fallout1: bool,
+    #[clap(subcommand)]
+    action: Action,
+}
+
+#[derive(Parser)]
+enum Action {
+    /// Converts prototype into its text form
+    Export,
+    /// Converts text form back into prototype
+    Import,
+    /// Converts prototype into its text form and back, checking that the result matches the input
+    Verify,
+}
+
+fn main() {
+    let options = Options::parse();
+
+    let input = match File::open(&options.input) {
+        Err(error) => {
+            eprintln!("Couldn't open input file: {:?}", error);
+            return;
+        }
+        Ok(value) => value,
+    };
+
+    let output = match File::create(&options.output) {
+        Err(error) => {
+            eprintln!("Couldn't create output file: {:?}", error);
+            return;
+        }
+        Ok(value) => value,
+    };
+
+    let mut reader = BufReader::with_capacity(1 * 1024 * 1024, input);
+    let mut writer = BufWriter::new(output);
+
+    let version = if options.fallout1 { Version::First } else { Version::Second };
+
+    let result = match options.action {
+        Action::Export => {
+            match pro::parse::prototype(&mut reader, &version, &pro::parse::Mode::Strict) {
+                Err(error) => Err(format!("{:?}", error)),
+                Ok(prototype) => serde_json::to_writer_pretty(&mut writer, &prototype)
+                    .map_err(|error| error.to_string())
+                    .and_then(|_| writeln!(writer).map_err(|error| error.to_string())),
+            }
+        }
+        Action::Import => {
+            match serde_json::from_reader::<_, pro::Prototype>(&mut reader) {
+                Err(error) => Err(error.to_string()),
+                Ok(prototype) => pro::write::prototype(&prototype, &mut writer)
+                    .map_err(|error| format!("{:?}", error)),
+            }
+        }
+        Action::Verify => {
+            let mut bytes = Vec::new();
+
+            reader.read_to_end(&mut bytes).map_err(|error| error.to_string())
+                .and_then(|_| pro::parse::prototype(&mut Cursor::new(&bytes), &version, &pro::parse::Mode::Strict)
+                    .map_err(|error| format!("{:?}", error)))
+                .and_then(|prototype| serde_json::to_string(&prototype).map_err(|error| error.to_string()))
+                .and_then(|text| serde_json::from_str::<pro::Prototype>(&text).map_err(|error| error.to_string()))
+                .and_then(|prototype| {
+                    let mut written = Vec::new();
+                    pro::write::prototype(&prototype, &mut written).map_err(|error| format!("{:?}", error))?;
+                    writer.write_all(&written).map_err(|error| error.to_string())?;
+
+                    match written.iter().zip(&bytes).position(|(left, right)| left != right) {
+                        None if written.len() == bytes.len() => {
+                            println!("Round trip is byte-identical.");
+                            Ok(())
+                        }
+                        None => Err(format!("Round trip wrote {} bytes out of {}.", written.len(), bytes.len())),
+                        Some(offset) => Err(format!("Round trip differs at byte {:#x}.", offset)),
+                    }
+                })
+        }
+    };
+
+    if let Err(error) = result.and_then(|_| writer.flush().map_err(|error| error.to_string())) {
+        eprintln!("Error occurred: {}", error);
+    }
+}
@@ -2,6 +2,7 @@ use std::fs::File;
 
 use clap::Parser;
 
+use ycresources::common::types::version::Version;
 use ycresources::formats::pro;
 
 pub(crate) mod print;
@@ -12,6 +13,9 @@ struct Options {
     /// Path to the input prototype file (.pro)
     #[clap(short, long)]
     input: String,
+    /// Treat the input as a Fallout 1 prototype
+    #[clap(long)]
+    fallout1: bool,
     #[clap(subcommand)]
     action: Action,
 }
@@ -35,7 +39,9 @@ fn main() {
 
     let mut reader = std::io::BufReader::with_capacity(1 * 1024 * 1024, file);
 
-    let prototype = match pro::parse::prototype(&mut reader) {
+    let version = if options.fallout1 { Version::First } else { Version::Second };
+
+    let prototype = match pro::parse::prototype(&mut reader, &version, &pro::parse::Mode::Strict) {
         Ok(value) => value,
         Err(error) => {
             eprintln!("Error occurred: {:?}", error);
@@ -1,3 +1,9 @@
 pub mod graphics;
 pub mod types;
 pub mod traits;
+
+#[cfg(feature = "serde")]
+pub(crate) mod array;
+
+#[cfg(feature = "serde")]
+pub(crate) mod sorted;
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error;
+
+// Serde supports arrays of up to 32 elements only, larger ones are represented as plain sequences.
+pub(crate) fn serialize<S: Serializer, T: Serialize, const N: usize>(array: &[T; N], serializer: S) ->
+Result<S::Ok, S::Error> {
+    serializer.collect_seq(array.iter())
+}
+
+pub(crate) fn deserialize<'de, D: Deserializer<'de>, T: Deserialize<'de>, const N: usize>(deserializer: D) ->
+Result<[T; N], D::Error> {
+    let items = Vec::<T>::deserialize(deserializer)?;
+    let count = items.len();
+
+    items.try_into().map_err(|_| D::Error::invalid_length(count, &N.to_string().as_str()))
+}
@@ -4,12 +4,16 @@ use crate::common::types::geometry::Scaled;
 
 pub mod builtin;
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Pixel<P> {
     pub red: Scaled<P, RangeInclusive<P>>,
     pub green: Scaled<P, RangeInclusive<P>>,
     pub blue: Scaled<P, RangeInclusive<P>>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AnimatedPixel {
     pub values: Vec<Pixel<u8>>,
     pub duration: std::time::Duration,
@@ -0,0 +1,26 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::hash::Hash;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// Hashed collections are written in their elements' order, so the output doesn't change between runs.
+pub(crate) fn set<S: Serializer, T: Serialize + Ord>(set: &HashSet<T>, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(set.iter().collect::<BTreeSet<_>>())
+}
+
+pub(crate) fn map<S: Serializer, K: Serialize + Ord, V: Serialize>(map: &HashMap<K, V>, serializer: S) ->
+Result<S::Ok, S::Error> {
+    serializer.collect_map(map.iter().collect::<BTreeMap<_, _>>())
+}
+
+// Maps with keys that aren't plain strings or numbers, like `Perk::Unknown(5)`, as a sequence of
+// `[key, value]` pairs, since formats like JSON only take such keys.
+pub(crate) fn pairs<S: Serializer, K: Serialize + Ord, V: Serialize>(map: &HashMap<K, V>, serializer: S) ->
+Result<S::Ok, S::Error> {
+    serializer.collect_seq(map.iter().collect::<BTreeMap<_, _>>())
+}
+
+pub(crate) fn from_pairs<'de, D, K, V>(deserializer: D) -> Result<HashMap<K, V>, D::Error>
+where D: Deserializer<'de>, K: Deserialize<'de> + Eq + Hash, V: Deserialize<'de> {
+    Ok(Vec::<(K, V)>::deserialize(deserializer)?.into_iter().collect())
+}
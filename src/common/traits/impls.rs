@@ -64,4 +64,16 @@ impl TryFrom<u32> for Orientation {
             return Err(Error::Format);
         }
     }
-}
\ No newline at end of file
+}
+impl From<&Coordinate<u8, Range<u8>>> for u32 {
+    fn from(value: &Coordinate<u8, Range<u8>>) -> Self {
+        const SIDE: u32 = 200;
+        value.y.value as u32 * SIDE + (SIDE - value.x.value as u32) % SIDE
+    }
+}
+
+impl From<&Orientation> for u32 {
+    fn from(value: &Orientation) -> Self {
+        value.scaled.value as u32
+    }
+}
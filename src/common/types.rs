@@ -2,3 +2,4 @@ pub mod errors;
 pub mod models;
 pub mod geometry;
 pub mod space;
+pub mod version;
@@ -1,18 +1,23 @@
 use std::ops::Range;
 
-#[derive(Debug, Hash, Eq, PartialEq)]
+pub mod hex;
+
+#[derive(Debug, Hash, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Scaled<V, S> {
     pub value: V,
     pub scale: S,
 }
 
-#[derive(Debug, Hash, Eq, PartialEq)]
+#[derive(Debug, Hash, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Coordinate<V, S> {
     pub x: Scaled<V, S>,
     pub y: Scaled<V, S>,
 }
 
-#[derive(Debug, Hash, Eq, PartialEq)]
+#[derive(Debug, Hash, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Orientation {
     pub scaled: Scaled<u8, Range<u8>>,
 }
\ No newline at end of file
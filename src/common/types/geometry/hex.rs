@@ -0,0 +1,172 @@
+use std::ops::Range;
+
+use crate::common::types::geometry::{Coordinate, Orientation, Scaled};
+
+pub const WIDTH: i32 = 32;
+pub const HEIGHT: i32 = 16;
+pub const STEP: i32 = 12; // vertical distance between rows
+
+pub const SIDE_LEN: i32 = 200;
+pub const TILE_SIDE_LEN: i32 = 100;
+
+// Screen steps between floor tiles, one column east and one row south.
+pub const TILE_COLUMN_STEP: (i32, i32) = (3 * WIDTH / 2, -STEP);
+pub const TILE_ROW_STEP: (i32, i32) = (WIDTH, 2 * STEP);
+
+pub type Hex = Coordinate<u8, Range<u8>>;
+pub type Tile = Coordinate<u8, Range<u8>>;
+
+// Axial coordinates, `q` grows to the east and `s` to the south-east.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct Axial {
+    q: i32,
+    s: i32,
+}
+
+const DIRECTIONS: [Axial; 6] = [
+    Axial { q: 1, s: -1 }, // north-east
+    Axial { q: 1, s: 0 }, // east
+    Axial { q: 0, s: 1 }, // south-east
+    Axial { q: -1, s: 1 }, // south-west
+    Axial { q: -1, s: 0 }, // west
+    Axial { q: 0, s: -1 }, // north-west
+];
+
+impl Axial {
+    fn from(hex: &Hex) -> Self {
+        let (column, row) = (hex.x.value as i32 - 1, hex.y.value as i32);
+        Self { q: column, s: row - column.div_euclid(2) }
+    }
+
+    fn hex(&self) -> Option<Hex> {
+        let (column, row) = (self.q, self.s + self.q.div_euclid(2));
+
+        if !(0..SIDE_LEN).contains(&column) || !(0..SIDE_LEN).contains(&row) { return None; }
+        Hex::try_from((row * SIDE_LEN + SIDE_LEN - 1 - column) as u32).ok()
+    }
+
+    fn add(&self, other: &Self, times: i32) -> Self {
+        Self { q: self.q + other.q * times, s: self.s + other.s * times }
+    }
+
+    fn round(q: f64, s: f64) -> Self {
+        let z = -q - s;
+        let (mut rq, mut rs, rz) = (q.round(), s.round(), z.round());
+        let (dq, ds, dz) = ((rq - q).abs(), (rs - s).abs(), (rz - z).abs());
+
+        if dq > ds && dq > dz {
+            rq = -rs - rz;
+        } else if ds > dz {
+            rs = -rq - rz;
+        }
+
+        Self { q: rq as i32, s: rs as i32 }
+    }
+}
+
+// Whether the hex survives a round trip through its grid index. An x of 200 is valid,
+// it is what hexes at the rows' ends decode to.
+pub fn is_valid(hex: &Hex) -> bool {
+    Hex::try_from(u32::from(hex)).ok().as_ref() == Some(hex)
+}
+
+// Top-left corner of the hex bounding box, relative to the grid origin.
+pub fn screen(hex: &Hex) -> (i32, i32) {
+    let axial = Axial::from(hex);
+    (WIDTH * axial.q + WIDTH / 2 * axial.s, STEP * axial.s)
+}
+
+pub fn center(hex: &Hex) -> (i32, i32) {
+    let (x, y) = screen(hex);
+    (x + WIDTH / 2, y + HEIGHT / 2)
+}
+
+pub fn hex(point: (i32, i32)) -> Option<Hex> {
+    let s = (point.1 - HEIGHT / 2) as f64 / STEP as f64;
+    let q = ((point.0 - WIDTH / 2) as f64 - (WIDTH / 2) as f64 * s) / WIDTH as f64;
+
+    Axial::round(q, s).hex()
+}
+
+pub fn neighbour(hex: &Hex, orientation: &Orientation) -> Option<Hex> {
+    let direction = DIRECTIONS.get(orientation.scaled.value as usize)?;
+    Axial::from(hex).add(direction, 1).hex()
+}
+
+pub fn neighbours(hex: &Hex) -> Vec<Hex> {
+    let axial = Axial::from(hex);
+    DIRECTIONS.iter().filter_map(|d| axial.add(d, 1).hex()).collect()
+}
+
+pub fn distance(from: &Hex, to: &Hex) -> u32 {
+    let (a, b) = (Axial::from(from), Axial::from(to));
+    let (dq, ds) = (b.q - a.q, b.s - a.s);
+
+    dq.abs().max(ds.abs()).max((dq + ds).abs()) as u32
+}
+
+pub fn line(from: &Hex, to: &Hex) -> Vec<Hex> {
+    let (a, b) = (Axial::from(from), Axial::from(to));
+    let count = distance(from, to);
+
+    // slight nudge keeps rounding stable on hex edges
+    const EPSILON: f64 = 1e-6;
+
+    (0..=count).filter_map(|step| {
+        let t = if count == 0 { 0.0 } else { step as f64 / count as f64 };
+
+        let q = a.q as f64 + EPSILON + (b.q - a.q) as f64 * t;
+        let s = a.s as f64 + EPSILON + (b.s - a.s) as f64 * t;
+
+        Axial::round(q, s).hex()
+    }).collect()
+}
+
+pub fn ring(center: &Hex, radius: u32) -> Vec<Hex> {
+    let center = Axial::from(center);
+    if radius == 0 { return center.hex().into_iter().collect(); }
+
+    let mut result = Vec::new();
+    let mut current = center.add(&DIRECTIONS[4], radius as i32);
+
+    for direction in &DIRECTIONS {
+        for _ in 0..radius {
+            if let Some(hex) = current.hex() { result.push(hex); }
+            current = current.add(direction, 1);
+        }
+    }
+
+    result
+}
+
+pub fn area(center: &Hex, radius: u32) -> Vec<Hex> {
+    (0..=radius).flat_map(|r| ring(center, r)).collect()
+}
+
+pub fn tile(hex: &Hex) -> Option<Tile> {
+    let (column, row) = (hex.x.value as i32 - 1, hex.y.value as i32);
+    let (column, row) = (column / 2, row / 2);
+    let scale = u8::MIN..TILE_SIDE_LEN as u8;
+
+    if !(0..TILE_SIDE_LEN).contains(&column) || !(0..TILE_SIDE_LEN).contains(&row) { return None; }
+
+    Some(Coordinate {
+        x: Scaled { value: column as u8, scale: scale.clone() },
+        y: Scaled { value: row as u8, scale },
+    })
+}
+
+pub fn from_tile(tile: &Tile) -> Option<Hex> {
+    let (column, row) = (tile.x.value as i32 * 2, tile.y.value as i32 * 2);
+    Axial { q: column, s: row - column.div_euclid(2) }.hex()
+}
+
+// Top-left corner of the tile art, relative to the same origin as hexes.
+pub fn tile_screen(tile: &Tile) -> (i32, i32) {
+    let (column, row) = (tile.x.value as i32, tile.y.value as i32);
+
+    (
+        TILE_COLUMN_STEP.0 * column + TILE_ROW_STEP.0 * row - WIDTH / 2,
+        TILE_COLUMN_STEP.1 * column + TILE_ROW_STEP.1 * row - 2,
+    )
+}
@@ -4,7 +4,8 @@ pub mod sprite;
 pub mod script;
 pub mod prototype;
 
-#[derive(Debug, Hash, Eq, PartialEq)]
+#[derive(Debug, Hash, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Identifier<Kind> {
     pub index: u16,
     pub kind: Kind,
@@ -19,4 +20,16 @@ impl<Kind> TryFrom<u32> for Identifier<Kind> where Kind: TryFrom<u32> {
             kind: Kind::try_from(value).map_err(|_| Error::Format)?,
         })
     }
-}
\ No newline at end of file
+}
+
+impl From<&Identifier<sprite::Kind>> for u32 {
+    fn from(value: &Identifier<sprite::Kind>) -> Self {
+        u32::from(&value.kind) | (value.index & 0b1111_1111_1111) as u32
+    }
+}
+
+impl From<&Identifier<script::Type>> for u32 {
+    fn from(value: &Identifier<script::Type>) -> Self {
+        u32::from(&value.kind) | (value.index & 0b1111_1111_1111) as u32
+    }
+}
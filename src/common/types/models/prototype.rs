@@ -16,4 +16,19 @@ impl TryFrom<u32> for pro::ObjectType {
             _ => return Err(Error::Format)
         })
     }
-}
\ No newline at end of file
+}
+
+impl From<&pro::ObjectInstance> for u32 {
+    fn from(value: &pro::ObjectInstance) -> Self {
+        let kind: u32 = match value {
+            pro::Type::Item(_) => 0,
+            pro::Type::Critter(_) => 1,
+            pro::Type::Scenery(_) => 2,
+            pro::Type::Wall(_) => 3,
+            pro::Type::Tile(_) => 4,
+            pro::Type::Misc(_) => 5,
+        };
+
+        kind << (u8::BITS * 3)
+    }
+}
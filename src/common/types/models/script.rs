@@ -1,6 +1,7 @@
 pub type Type = Kind<(), (), (), (), ()>;
 
-#[derive(Debug, Hash, Eq, PartialEq)]
+#[derive(Debug, Hash, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Kind<Sys, Sp, T, I, C> {
     System(Sys),
     Spatial(Sp),
@@ -22,4 +23,18 @@ impl TryFrom<u32> for Type {
             _ => return Err(Self::Error::Format)
         })
     }
-}
\ No newline at end of file
+}
+
+impl From<&Type> for u32 {
+    fn from(value: &Type) -> Self {
+        let kind: u32 = match value {
+            Type::System(_) => 0,
+            Type::Spatial(_) => 1,
+            Type::Timed(_) => 2,
+            Type::Item(_) => 3,
+            Type::Critter(_) => 4,
+        };
+
+        kind << (u8::BITS * 3)
+    }
+}
@@ -3,7 +3,10 @@ use crate::common::types::geometry;
 use crate::formats::pro::object::common::critter;
 use crate::formats::pro::object::item::weapon;
 
-#[derive(Debug, Eq, PartialEq)]
+mod suffix;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Kind {
     Item,
     Critter(Option<geometry::Orientation>, critter::Animation, Option<weapon::Animation>),
@@ -52,4 +55,57 @@ impl TryFrom<u32> for Kind {
             _ => return Err(Self::Error::Format)
         })
     }
-}
\ No newline at end of file
+}
+
+impl From<&Kind> for u32 {
+    fn from(value: &Kind) -> Self {
+        let kind: u32 = match value {
+            Kind::Item => 0,
+            Kind::Critter(orientation, animation, weapon) => {
+                return 1 << (u8::BITS * 3)
+                    | orientation.as_ref().map_or(0, |o| u32::from(o) + 1) << 28
+                    | (u8::from(animation) as u32) << 16
+                    | weapon.as_ref().map_or(0, u32::from) << 12;
+            }
+            Kind::Scenery => 2,
+            Kind::Wall => 3,
+            Kind::Tile => 4,
+            Kind::Misc => 5,
+            Kind::Interface => 6,
+            Kind::Inventory => 7,
+            Kind::Head => 8,
+            Kind::Background => 9,
+            Kind::Skilldex => 10,
+        };
+
+        kind << (u8::BITS * 3)
+    }
+}
+
+impl Kind {
+    // Two letters following a critter's base name, e.g. `aa` in `hmjmpsaa.frm`.
+    pub fn suffix(&self) -> Option<(char, char)> {
+        match self {
+            Self::Critter(_, animation, weapon) => suffix::detect(weapon, animation),
+            _ => None,
+        }
+    }
+
+    // Splits a critter's file name into its base name and the kind, `.fr0-5` files carry an orientation.
+    pub fn critter(filename: &str) -> Option<(&str, Self)> {
+        let (stem, extension) = filename.rsplit_once('.')?;
+
+        let orientation = match extension.to_ascii_lowercase().as_str() {
+            "frm" => None,
+            other => Some(geometry::Orientation::try_from(other.strip_prefix("fr")?.parse::<u32>().ok()?).ok()?),
+        };
+
+        if stem.len() < 3 || !stem.is_char_boundary(stem.len() - 2) { return None; }
+        let (base, code) = stem.split_at(stem.len() - 2);
+
+        let mut code = code.chars().map(|c| c.to_ascii_lowercase());
+        let (animation, weapon) = suffix::animation((code.next()?, code.next()?))?;
+
+        Some((base, Self::Critter(orientation, animation, weapon)))
+    }
+}
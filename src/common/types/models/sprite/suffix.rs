@@ -1,9 +1,13 @@
-use ycresources::formats::pro::object::common::critter;
-use ycresources::formats::pro::object::item::weapon;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use crate::common::traits::TryFromOptional;
+use crate::formats::pro::object::common::critter;
+use crate::formats::pro::object::item::weapon;
 
 pub(crate) fn detect(weapon: &Option<weapon::Animation>, animation: &critter::Animation) -> Option<(char, char)> {
     fn w_code(weapon: &Option<weapon::Animation>) -> char {
-        return (b'c' + match weapon {
+        (b'c' + match weapon {
             None => return b'a' as char,
             Some(weapon::Animation::Knife) => 1u8,
             Some(weapon::Animation::Club) => 2,
@@ -15,7 +19,7 @@ pub(crate) fn detect(weapon: &Option<weapon::Animation>, animation: &critter::An
             Some(weapon::Animation::BigGun) => 8,
             Some(weapon::Animation::Minigun) => 9,
             Some(weapon::Animation::RocketLauncher) => 10,
-        }) as char;
+        }) as char
     }
 
     fn a_char(a: &critter::Animation) -> char {
@@ -87,10 +91,9 @@ pub(crate) fn detect(weapon: &Option<weapon::Animation>, animation: &critter::An
         }) as char
     }
 
-    fn a_code(animation: &critter::Animation, base: &critter::Animation, root: char) -> char {
-        let c = root as u8 + a_char(animation) as u8 - a_char(base) as u8;
-        assert!(c.is_ascii());
-        c as char
+    fn a_code(animation: &critter::Animation, base: &critter::Animation, root: char) -> Option<char> {
+        let c = (root as u8).checked_add(a_char(animation) as u8)?.checked_sub(a_char(base) as u8)?;
+        c.is_ascii_lowercase().then_some(c as char)
     }
 
     Some(match animation {
@@ -99,13 +102,13 @@ pub(crate) fn detect(weapon: &Option<weapon::Animation>, animation: &critter::An
         _ if a_char(animation) as u8 >= a_char(&critter::Animation::TakeOut) as u8 &&
             a_char(animation) as u8 <= a_char(&critter::Animation::FireContinuous) as u8 => {
             if weapon.is_none() { return None; }
-            (w_code(weapon), a_code(animation, &critter::Animation::TakeOut, 'c'))
+            (w_code(weapon), a_code(animation, &critter::Animation::TakeOut, 'c')?)
         }
         critter::Animation::CalledShotPic => ('n', 'a'),
         _ if a_char(animation) as u8 >= a_char(&critter::Animation::FallBackSf) as u8 =>
-            ('r', a_code(animation, &critter::Animation::FallBackSf, 'a')),
+            ('r', a_code(animation, &critter::Animation::FallBackSf, 'a')?),
         _ if a_char(animation) as u8 >= a_char(&critter::Animation::FallBack) as u8 =>
-            ('b', a_code(animation, &critter::Animation::FallBack, 'a')),
+            ('b', a_code(animation, &critter::Animation::FallBack, 'a')?),
         critter::Animation::Throw => match weapon {
             Some(weapon::Animation::Knife) | Some(weapon::Animation::Spear) => (w_code(weapon), 'm'),
             _ => (w_code(&None), 's')
@@ -115,9 +118,30 @@ pub(crate) fn detect(weapon: &Option<weapon::Animation>, animation: &critter::An
                 critter::Animation::Stand | critter::Animation::Walk => w_code(weapon),
                 _ => w_code(&None),
             },
-             a_code(animation, &critter::Animation::Stand, 'a'))
+             a_code(animation, &critter::Animation::Stand, 'a')?)
         }
         _ if weapon.is_none() => { (w_code(weapon), 'n') }
         _ => (w_code(weapon), 'e')
-    }).map(|(c1, c2)| (c1, c2))
-}
\ No newline at end of file
+    })
+}
+
+type Pair = (critter::Animation, Option<weapon::Animation>);
+
+// Reverse of `detect`, built once, the first animation and weapon producing a suffix own it.
+pub(crate) fn animation(suffix: (char, char)) -> Option<Pair> {
+    static TABLE: OnceLock<HashMap<(char, char), Pair>> = OnceLock::new();
+
+    TABLE.get_or_init(|| {
+        let mut table = HashMap::new();
+
+        for animation in (u8::MIN..=u8::MAX).filter_map(|value| critter::Animation::try_from(value).ok()) {
+            for weapon in (0..=0x0A).filter_map(|value| weapon::Animation::try_from_optional(value, 0x00).ok()) {
+                if let Some(suffix) = detect(&weapon, &animation) {
+                    table.entry(suffix).or_insert((animation, weapon));
+                }
+            }
+        }
+
+        table
+    }).get(&suffix).cloned()
+}
@@ -2,7 +2,8 @@ use std::ops::Range;
 
 use crate::common::types::geometry::Scaled;
 
-#[derive(Debug, Hash, Eq, PartialEq)]
+#[derive(Debug, Hash, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Elevation {
     pub level: Scaled<u8, Range<u8>>,
 }
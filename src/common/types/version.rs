@@ -0,0 +1,22 @@
+use super::errors::Error;
+
+// Game revision, decides layouts of versioned structures in maps, prototypes and saves.
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Version {
+    First,
+    Second,
+}
+
+// Numbers stored in maps' headers.
+impl TryFrom<u32> for Version {
+    type Error = Error;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            19 => Ok(Self::First),
+            20 => Ok(Self::Second),
+            _ => Err(Error::Format)
+        }
+    }
+}
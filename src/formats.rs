@@ -1,6 +1,13 @@
 pub mod aaf;
+pub mod acm;
 pub mod dat;
 pub mod frm;
+pub mod gam;
+pub mod gcd;
 pub mod pal;
 pub mod pro;
+pub mod rix;
+pub mod save;
 pub mod map;
+pub mod mve;
+pub mod worldmap;
@@ -4,6 +4,8 @@ use crate::common::types::geometry::Scaled;
 
 pub mod parse;
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Glyph {
     pub width: u16,
     pub height: u16,
@@ -11,14 +13,19 @@ pub struct Glyph {
     pub dots: Vec<Scaled<usize, Range<usize>>>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Spacing {
     pub vertical: u16,
     pub horizontal: u16,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Font {
     pub height: u16,
     pub spacing: Spacing,
 
+    #[cfg_attr(feature = "serde", serde(with = "crate::common::array"))]
     pub glyphs: [Glyph; 256],
 }
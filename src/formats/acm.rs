@@ -0,0 +1,15 @@
+pub mod parse;
+pub mod decode;
+
+pub type Sample = i16;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Header {
+    pub channels: u16,
+    pub rate: u16,
+    pub samples: u32,
+
+    pub level: u8,
+    pub rows: u16,
+}
@@ -0,0 +1,125 @@
+use std::io::{ErrorKind, Read};
+
+use crate::common::types::errors::Error;
+
+use super::{Header, Sample};
+
+mod bits;
+mod fill;
+mod juggle;
+
+pub struct Decoder<S> {
+    pub header: Header,
+
+    bits: bits::Reader<S>,
+    block: Vec<i32>,
+    wrap: Vec<i32>,
+
+    position: usize,
+    remaining: u32,
+    pending: Option<u8>,
+}
+
+impl<S: Read> Decoder<S> {
+    pub(crate) fn new(source: S, header: Header) -> Self {
+        let columns = 1usize << header.level;
+        let rows = header.rows as usize;
+
+        Self {
+            bits: bits::Reader::new(source),
+            block: vec![0; columns * rows],
+            wrap: vec![0; (2 * columns).saturating_sub(2)],
+            position: columns * rows,
+            remaining: header.samples,
+            pending: None,
+            header,
+        }
+    }
+
+    fn unpack(&mut self) -> Result<(), Error> {
+        self.bits.read(4)?; // amplitudes' power, values are scaled by the step directly
+        let step = self.bits.read(16)? as i32;
+
+        let columns = 1usize << self.header.level;
+        let rows = self.header.rows as usize;
+
+        for index in 0..columns {
+            let mut column = fill::Column {
+                block: &mut self.block,
+                columns,
+                index,
+                rows,
+                step,
+            };
+
+            fill::column(&mut self.bits, &mut column)?;
+        }
+
+        juggle::block(&mut self.block, &mut self.wrap, self.header.level, rows);
+
+        Ok(())
+    }
+}
+
+impl<S: Read> Iterator for Decoder<S> {
+    type Item = Result<Sample, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 { return None; }
+
+        if self.position >= self.block.len() {
+            if let Err(error) = self.unpack() {
+                self.remaining = 0;
+                return Some(Err(error));
+            }
+
+            self.position = 0;
+        }
+
+        let value = self.block[self.position] >> self.header.level;
+
+        self.position += 1;
+        self.remaining -= 1;
+
+        Some(Ok(value as Sample))
+    }
+}
+
+// Produces interleaved signed 16-bit little-endian PCM.
+impl<S: Read> Read for Decoder<S> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let mut written = 0;
+
+        while written < buf.len() {
+            if let Some(byte) = self.pending.take() {
+                buf[written] = byte;
+                written += 1;
+
+                continue;
+            }
+
+            match self.next() {
+                None => break,
+                Some(Err(Error::IO(error))) => return Err(error),
+                Some(Err(Error::Format)) => {
+                    return Err(std::io::Error::new(ErrorKind::InvalidData, "Invalid ACM block."));
+                }
+                Some(Ok(sample)) => {
+                    let [low, high] = sample.to_le_bytes();
+
+                    buf[written] = low;
+                    written += 1;
+
+                    if written < buf.len() {
+                        buf[written] = high;
+                        written += 1;
+                    } else {
+                        self.pending = Some(high);
+                    }
+                }
+            }
+        }
+
+        Ok(written)
+    }
+}
@@ -0,0 +1,34 @@
+use std::io::Read;
+
+use byteorder::ReadBytesExt;
+
+use crate::common::types::errors::Error;
+
+// Values are packed starting from the least significant bit of each byte.
+pub(crate) struct Reader<S> {
+    source: S,
+    data: u32,
+    available: u32,
+}
+
+impl<S: Read> Reader<S> {
+    pub(crate) fn new(source: S) -> Self {
+        Self { source, data: 0, available: 0 }
+    }
+
+    pub(crate) fn read(&mut self, count: u32) -> Result<u32, Error> {
+        debug_assert!(count <= 16);
+
+        while self.available < count {
+            self.data |= (self.source.read_u8()? as u32) << self.available;
+            self.available += 8;
+        }
+
+        let value = self.data & ((1 << count) - 1);
+
+        self.data >>= count;
+        self.available -= count;
+
+        Ok(value)
+    }
+}
@@ -0,0 +1,124 @@
+use std::io::Read;
+
+use crate::common::types::errors::Error;
+
+use super::bits::Reader;
+
+const MAP_1BIT: [i32; 2] = [-1, 1];
+const MAP_2BIT_NEAR: [i32; 4] = [-2, -1, 1, 2];
+const MAP_2BIT_FAR: [i32; 4] = [-3, -2, 2, 3];
+const MAP_3BIT: [i32; 8] = [-4, -3, -2, -1, 1, 2, 3, 4];
+
+pub(crate) struct Column<'a> {
+    pub(crate) block: &'a mut [i32],
+    pub(crate) columns: usize,
+    pub(crate) index: usize,
+    pub(crate) rows: usize,
+    pub(crate) step: i32,
+}
+
+impl Column<'_> {
+    fn set(&mut self, row: usize, value: i32) {
+        self.block[row * self.columns + self.index] = value.wrapping_mul(self.step);
+    }
+}
+
+pub(crate) fn column<S: Read>(bits: &mut Reader<S>, column: &mut Column) -> Result<(), Error> {
+    match bits.read(5)? {
+        0 => {
+            for row in 0..column.rows { column.set(row, 0); }
+            Ok(())
+        }
+        width @ 3..=16 => {
+            let middle = 1 << (width - 1);
+            for row in 0..column.rows { column.set(row, bits.read(width)? as i32 - middle); }
+            Ok(())
+        }
+        17 => coded(bits, column, true, k12),
+        18 => coded(bits, column, false, k12),
+        19 => packed(bits, column, 5, 3, 3),
+        20 => coded(bits, column, true, k23),
+        21 => coded(bits, column, false, k23),
+        22 => packed(bits, column, 7, 5, 3),
+        23 => coded(bits, column, true, k34),
+        24 => coded(bits, column, false, k34),
+        26 => coded(bits, column, true, k44),
+        27 => coded(bits, column, false, k44),
+        29 => packed(bits, column, 7, 11, 2),
+        _ => Err(Error::Format),
+    }
+}
+
+// Optionally, single leading zero bit stands for two zero values in a row.
+fn coded<S: Read>(
+    bits: &mut Reader<S>,
+    column: &mut Column,
+    zeros: bool,
+    decode: fn(&mut Reader<S>) -> Result<i32, Error>,
+) -> Result<(), Error> {
+    let mut row = 0;
+
+    while row < column.rows {
+        if zeros && bits.read(1)? == 0 {
+            column.set(row, 0);
+            if row + 1 < column.rows { column.set(row + 1, 0); }
+
+            row += 2;
+            continue;
+        }
+
+        let value = decode(bits)?;
+        column.set(row, value);
+
+        row += 1;
+    }
+
+    Ok(())
+}
+
+fn k12<S: Read>(bits: &mut Reader<S>) -> Result<i32, Error> {
+    Ok(if bits.read(1)? == 0 { 0 } else { MAP_1BIT[bits.read(1)? as usize] })
+}
+
+fn k23<S: Read>(bits: &mut Reader<S>) -> Result<i32, Error> {
+    Ok(if bits.read(1)? == 0 { 0 } else { MAP_2BIT_NEAR[bits.read(2)? as usize] })
+}
+
+fn k34<S: Read>(bits: &mut Reader<S>) -> Result<i32, Error> {
+    Ok(if bits.read(1)? == 0 { 0 } else if bits.read(1)? == 0 {
+        MAP_1BIT[bits.read(1)? as usize]
+    } else {
+        MAP_2BIT_FAR[bits.read(2)? as usize]
+    })
+}
+
+fn k44<S: Read>(bits: &mut Reader<S>) -> Result<i32, Error> {
+    Ok(if bits.read(1)? == 0 { 0 } else { MAP_3BIT[bits.read(3)? as usize] })
+}
+
+// Several values stored as digits of a single number, centered around zero.
+fn packed<S: Read>(
+    bits: &mut Reader<S>,
+    column: &mut Column,
+    width: u32,
+    base: i32,
+    digits: u32,
+) -> Result<(), Error> {
+    let mut row = 0;
+
+    while row < column.rows {
+        let mut value = bits.read(width)? as i32;
+        if value >= base.pow(digits) { return Err(Error::Format); }
+
+        for _ in 0..digits {
+            if row >= column.rows { break; }
+
+            column.set(row, value % base - base / 2);
+            value /= base;
+
+            row += 1;
+        }
+    }
+
+    Ok(())
+}
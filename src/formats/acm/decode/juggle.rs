@@ -0,0 +1,62 @@
+use std::cmp::min;
+
+// Inverse subband transform, applied in place over the whole block.
+pub(crate) fn block(block: &mut [i32], wrap: &mut [i32], level: u8, rows: usize) {
+    if level == 0 { return; }
+
+    let columns = 1usize << level;
+    let step = if level > 9 { 1 } else { (2048 >> level) - 2 };
+
+    let mut remaining = rows;
+    let mut offset = 0;
+
+    loop {
+        let mut wrapped = 0;
+        let mut length = columns / 2;
+        let mut count = min(step, remaining) * 2;
+
+        pass(&mut wrap[wrapped..], &mut block[offset..], length, count);
+        wrapped += length * 2;
+
+        for index in 0..count {
+            let value = &mut block[offset + index * length];
+            *value = value.wrapping_add(1);
+        }
+
+        while length > 1 {
+            length >>= 1;
+            count <<= 1;
+
+            pass(&mut wrap[wrapped..], &mut block[offset..], length, count);
+            wrapped += length * 2;
+        }
+
+        if remaining <= step { break; }
+
+        offset += step << level;
+        remaining -= step;
+    }
+}
+
+fn pass(wrap: &mut [i32], block: &mut [i32], length: usize, count: usize) {
+    for index in 0..length {
+        let mut position = index;
+        let (mut r0, mut r1) = (wrap[2 * index], wrap[2 * index + 1]);
+
+        for _ in 0..count / 2 {
+            let r2 = block[position];
+            block[position] = r1.wrapping_mul(2).wrapping_add(r0.wrapping_add(r2));
+            position += length;
+
+            let r3 = block[position];
+            block[position] = r2.wrapping_mul(2).wrapping_sub(r1.wrapping_add(r3));
+            position += length;
+
+            r0 = r2;
+            r1 = r3;
+        }
+
+        wrap[2 * index] = r0;
+        wrap[2 * index + 1] = r1;
+    }
+}
@@ -0,0 +1,31 @@
+use std::io::{Read, Seek, SeekFrom};
+
+use byteorder::{LittleEndian, ReadBytesExt};
+
+use crate::common::types::errors::Error;
+
+use super::decode::Decoder;
+use super::Header;
+
+pub fn header<S: Read + Seek>(source: &mut S) -> Result<Header, Error> {
+    source.seek(SeekFrom::Start(0))?;
+    if source.read_u32::<LittleEndian>()? != 0x01032897 { return Err(Error::Format); }
+
+    let samples = source.read_u32::<LittleEndian>()?;
+    let channels = source.read_u16::<LittleEndian>()?;
+    let rate = source.read_u16::<LittleEndian>()?;
+
+    let packed = source.read_u16::<LittleEndian>()?;
+
+    let level = (packed & 0x0F) as u8;
+    let rows = packed >> 4;
+
+    if channels == 0 || rows == 0 { return Err(Error::Format); }
+
+    Ok(Header { channels, rate, samples, level, rows })
+}
+
+pub fn decoder<S: Read + Seek>(mut source: S) -> Result<Decoder<S>, Error> {
+    let header = header(&mut source)?;
+    Ok(Decoder::new(source, header))
+}
@@ -1,12 +1,16 @@
 pub mod extract;
 pub mod parse;
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct File {
     pub name: String,
     pub size: u32,
     pub(crate) range: std::ops::Range<u32>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Directory {
     pub name: String,
     pub files: Vec<File>,
@@ -1,11 +1,15 @@
 pub mod merge;
 pub mod parse;
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Size {
     pub width: u16,
     pub height: u16,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Shift {
     pub x: i16,
     pub y: i16,
@@ -13,12 +17,16 @@ pub struct Shift {
 
 pub type ColorIndex = u8;
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Frame {
     pub size: Size,
     pub shift: Shift,
     pub indexes: Vec<ColorIndex>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Animation {
     pub shift: Shift,
     pub frames: Vec<Frame>,
@@ -27,6 +35,8 @@ pub struct Animation {
 pub type FrameIndex = u16;
 pub type AnimationIndex = u8;
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Sprite {
     pub fps: u16,
     pub count: u16,
@@ -1,6 +1,6 @@
 use super::*;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Error { Inconsistency }
 
 pub fn sprites(source: [Sprite; 6]) -> Result<Sprite, Error> {
@@ -0,0 +1,22 @@
+pub mod parse;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Variable {
+    pub name: String,
+    pub value: i32,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Definitions {
+    pub game: Vec<Variable>,
+    pub map: Vec<Variable>,
+}
+
+// Pairs raw values, e.g. `map.variables.global` with `definitions.map`, with their names.
+pub fn attach<'a>(variables: &'a [Variable], values: &[i32]) -> Vec<(Option<&'a str>, i32)> {
+    values.iter().enumerate().map(|(index, value)| {
+        (variables.get(index).map(|v| v.name.as_str()), *value)
+    }).collect()
+}
@@ -0,0 +1,46 @@
+use std::io::{Read, Seek, SeekFrom};
+
+use crate::common::types::errors::Error;
+
+use super::*;
+
+enum Section { Game, Map }
+
+pub fn definitions<S: Read + Seek>(source: &mut S) -> Result<Definitions, Error> {
+    source.seek(SeekFrom::Start(0))?;
+
+    let mut bytes = Vec::new();
+    source.read_to_end(&mut bytes)?;
+
+    let text = std::str::from_utf8(&bytes).map_err(|_| Error::Format)?;
+
+    let mut definitions = Definitions::default();
+    let mut section = None;
+
+    for line in text.lines() {
+        let line = line.split("//").next().unwrap_or_default().trim();
+        if line.is_empty() { continue; }
+
+        match line {
+            "GAME_GLOBAL_VARS:" => section = Some(Section::Game),
+            "MAP_GLOBAL_VARS:" => section = Some(Section::Map),
+            _ => {
+                let (name, value) = line.split_once(":=").ok_or(Error::Format)?;
+                let value = value.split(';').next().unwrap_or_default().trim();
+
+                let variable = Variable {
+                    name: String::from(name.trim()),
+                    value: value.parse().map_err(|_| Error::Format)?,
+                };
+
+                match section {
+                    Some(Section::Game) => definitions.game.push(variable),
+                    Some(Section::Map) => definitions.map.push(variable),
+                    None => return Err(Error::Format),
+                }
+            }
+        }
+    }
+
+    Ok(definitions)
+}
@@ -0,0 +1,15 @@
+use crate::formats::pro::object;
+use crate::formats::pro::object::common::critter::{Skill, Trait};
+
+pub mod parse;
+pub mod write;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Character {
+    pub name: String,
+    pub critter: object::critter::Data,
+    pub tagged: Vec<Skill>,
+    pub traits: Vec<Trait>,
+    pub points: u32,
+}
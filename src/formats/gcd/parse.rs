@@ -0,0 +1,41 @@
+use std::io::{Read, Seek, SeekFrom};
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+use crate::common::traits::TryFromOptional;
+use crate::common::types::errors::Error;
+use crate::common::types::version::Version;
+use crate::formats::pro::object::common::critter::{Skill, Trait};
+use crate::formats::pro::parse::instance::critter;
+
+use super::*;
+
+pub fn character<S: Read + Seek>(source: &mut S, version: &Version) -> Result<Character, Error> {
+    source.seek(SeekFrom::Start(0))?;
+
+    let critter = critter::data(source, version)?;
+
+    let mut name_bytes = [0u8; 32];
+    source.read_exact(&mut name_bytes)?;
+
+    let end = name_bytes.iter().position(|b| *b == 0).unwrap_or(name_bytes.len());
+    let name = String::from(std::str::from_utf8(&name_bytes[..end]).map_err(|_| Error::Format)?);
+
+    let mut tagged = Vec::new();
+    for _ in 0..4 {
+        if let Some(skill) = Skill::try_from_optional(source.read_i32::<BigEndian>()?, -1)? {
+            tagged.push(skill);
+        }
+    }
+
+    let mut traits = Vec::new();
+    for _ in 0..2 {
+        if let Some(r#trait) = Trait::try_from_optional(source.read_i32::<BigEndian>()?, -1)? {
+            traits.push(r#trait);
+        }
+    }
+
+    let points = source.read_u32::<BigEndian>()?;
+
+    Ok(Character { name, critter, tagged, traits, points })
+}
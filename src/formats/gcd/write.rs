@@ -0,0 +1,33 @@
+use std::io::Write;
+
+use byteorder::{BigEndian, WriteBytesExt};
+
+use crate::common::types::errors::Error;
+use crate::formats::pro::write::instance::critter;
+
+use super::*;
+
+pub fn character<W: Write>(character: &Character, destination: &mut W) -> Result<(), Error> {
+    if character.tagged.len() > 4 || character.traits.len() > 2 { return Err(Error::Format); }
+
+    critter::data(&character.critter, destination)?;
+
+    let name = character.name.as_bytes();
+    if name.len() >= 32 { return Err(Error::Format); }
+
+    let mut name_bytes = [0u8; 32];
+    name_bytes[..name.len()].copy_from_slice(name);
+    destination.write_all(&name_bytes)?;
+
+    for index in 0..4 {
+        destination.write_i32::<BigEndian>(character.tagged.get(index).map_or(-1, |s| i32::from(*s)))?;
+    }
+
+    for index in 0..2 {
+        destination.write_i32::<BigEndian>(character.traits.get(index).map_or(-1, |t| i32::from(*t)))?;
+    }
+
+    destination.write_u32::<BigEndian>(character.points)?;
+
+    Ok(())
+}
@@ -1,13 +1,23 @@
 use std::collections::HashSet;
 
-pub mod parse;
+use crate::common::types::version::Version;
 
-#[derive(Debug)]
+pub mod parse;
+pub mod diff;
+pub mod edit;
+pub mod index;
+pub mod lint;
+pub mod path;
+pub mod sight;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Map {
     pub id: u32,
-    pub version: u32,
+    pub version: Version,
     pub filename: String,
 
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::common::sorted::set"))]
     pub flags: HashSet<common::Flag>,
 
     pub entrance: location::Grid,
@@ -22,10 +32,12 @@ pub struct Map {
 }
 
 pub mod common {
-    #[derive(Debug, PartialEq, Eq, Hash)]
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub enum Flag { Save }
 
-    #[derive(Debug)]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct Variables {
         pub local: Vec<i32>,
         pub global: Vec<i32>,
@@ -38,14 +50,16 @@ pub mod location {
     use crate::common::types::geometry::{Coordinate, Orientation};
     use crate::common::types::space::Elevation;
 
-    #[derive(Debug, Eq, PartialEq)]
+    #[derive(Debug, Eq, PartialEq, Clone)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct Grid {
         pub position: Coordinate<u8, Range<u8>>,
         pub elevation: Elevation,
         pub orientation: Orientation,
     }
 
-    #[derive(Debug, Eq, PartialEq)]
+    #[derive(Debug, Eq, PartialEq, Clone)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct Screen {
         pub position: Coordinate<i32, RangeInclusive<i32>>,
         pub correction: Coordinate<i32, RangeInclusive<i32>>,
@@ -58,13 +72,22 @@ pub mod tiles {
     use crate::common::types::geometry::Coordinate;
     use crate::common::types::space::Elevation;
 
-    #[derive(Debug, Eq, PartialEq)]
+    #[derive(Debug, Eq, PartialEq, Clone)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct Instance<V, S> {
         pub id: u16,
         pub position: Coordinate<V, Range<S>>,
     }
 
-    #[derive(Debug)]
+    #[derive(Debug, Eq, PartialEq, Copy, Clone, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum Layer {
+        Floor,
+        Ceiling,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct Group {
         pub floor: Vec<Instance<u8, u8>>,
         pub ceiling: Vec<Instance<u8, u8>>,
@@ -76,19 +99,22 @@ pub mod blueprint {
     pub mod script {
         use crate::common::types::models::script::Kind;
 
-        #[derive(Debug, Hash, Eq, PartialEq)]
+        #[derive(Debug, Hash, Eq, PartialEq, Clone)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct Connections {
             pub program_id: Option<u32>,
             pub object_id: Option<u32>,
         }
 
-        #[derive(Debug, Hash, Eq, PartialEq)]
+        #[derive(Debug, Hash, Eq, PartialEq, Clone)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct Variables {
             pub offset: u32,
             pub count: u32,
         }
 
-        #[derive(Debug, Hash, Eq, PartialEq)]
+        #[derive(Debug, Hash, Eq, PartialEq, Clone)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct Instance {
             pub id: u16,
             pub kind: Kind<(), spatial::Instance, time::Instance, (), ()>,
@@ -100,7 +126,8 @@ pub mod blueprint {
             use crate::common::types::geometry::Coordinate;
             use crate::common::types::space::Elevation;
 
-            #[derive(Debug, Hash, Eq, PartialEq)]
+            #[derive(Debug, Hash, Eq, PartialEq, Clone)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct Instance {
                 pub position: Coordinate<u8, std::ops::Range<u8>>,
                 pub distance: u16,
@@ -109,7 +136,8 @@ pub mod blueprint {
         }
 
         pub mod time {
-            #[derive(Debug, Hash, Eq, PartialEq)]
+            #[derive(Debug, Hash, Eq, PartialEq, Clone)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct Instance {
                 pub duration: std::time::Duration,
             }
@@ -123,28 +151,40 @@ pub mod blueprint {
         use crate::formats::map::location;
         use crate::formats::pro::{meta, ObjectPatch, ObjectType};
 
-        #[derive(Debug, Eq, PartialEq)]
+        #[derive(Debug, Eq, PartialEq, Clone)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct Instance {
             pub id: Identifier<ObjectType>,
             pub patch: Patch,
             pub location: Location,
             pub appearance: Appearance,
             pub inventory: inventory::Instance,
+            pub connections: Connections,
         }
 
-        #[derive(Debug, Eq, PartialEq)]
+        // `object_id` is what scripts' `object_id` refer to.
+        #[derive(Debug, Eq, PartialEq, Clone)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub struct Connections {
+            pub object_id: u32,
+        }
+
+        #[derive(Debug, Eq, PartialEq, Clone)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct Patch {
             pub meta: meta::Patch,
             pub object: ObjectPatch,
         }
 
-        #[derive(Debug, Eq, PartialEq)]
+        #[derive(Debug, Eq, PartialEq, Clone)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct Location {
             pub grid: Option<location::Grid>,
             pub screen: location::Screen,
         }
 
-        #[derive(Debug, Eq, PartialEq)]
+        #[derive(Debug, Eq, PartialEq, Clone)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct Appearance {
             pub sprite: Identifier<models::sprite::Kind>,
             pub current: Option<FrameIndex>,
@@ -152,7 +192,13 @@ pub mod blueprint {
 
         pub mod inventory {
             pub type Item = super::Instance;
-            pub type Instance = Vec<Option<Item>>;
+
+            #[derive(Debug, Eq, PartialEq, Clone)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+            pub struct Instance {
+                pub capacity: u32,
+                pub items: Vec<Option<Item>>, // may exceed capacity in shipped maps
+            }
         }
     }
 }
\ No newline at end of file
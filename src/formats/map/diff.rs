@@ -0,0 +1,267 @@
+use std::collections::HashMap;
+
+use crate::common::types::geometry::hex;
+use crate::common::types::version::Version;
+
+use super::{common, location, Map};
+use super::tiles::Layer;
+use super::blueprint::prototype;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Change<T> {
+    pub before: T,
+    pub after: T,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Header {
+    Id(Change<u32>),
+    Version(Change<Version>),
+    Filename(Change<String>),
+    Flags(Change<Vec<common::Flag>>), // sorted
+    Entrance(Change<location::Grid>),
+    Ticks(Change<u32>),
+    Darkness(Change<u32>),
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tile {
+    pub elevation: u8,
+    pub layer: Layer,
+    pub position: (u8, u8),
+    pub id: Change<Option<u16>>,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Field {
+    Location,
+    Patch,
+    Appearance,
+    Inventory,
+}
+
+// Objects are referenced by their indexes in `Map::prototypes` of either side.
+#[derive(Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Object {
+    Added(usize),
+    Removed(usize),
+    Changed { before: usize, after: usize, fields: Vec<Field> },
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Script {
+    Added(u16),
+    Removed(u16),
+    Changed(u16),
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Scope {
+    Local,
+    Global,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Variable {
+    pub scope: Scope,
+    pub index: usize,
+    pub value: Change<Option<i32>>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Diff {
+    pub header: Vec<Header>,
+    pub tiles: Vec<Tile>,
+    pub objects: Vec<Object>,
+    pub scripts: Vec<Script>,
+    pub variables: Vec<Variable>,
+}
+
+impl Diff {
+    pub fn is_empty(&self) -> bool {
+        self.header.is_empty() &&
+            self.tiles.is_empty() &&
+            self.objects.is_empty() &&
+            self.scripts.is_empty() &&
+            self.variables.is_empty()
+    }
+}
+
+pub fn diff(before: &Map, after: &Map) -> Diff {
+    Diff {
+        header: header(before, after),
+        tiles: tiles(before, after),
+        objects: objects(&before.prototypes, &after.prototypes),
+        scripts: scripts(before, after),
+        variables: [
+            (Scope::Local, &before.variables.local, &after.variables.local),
+            (Scope::Global, &before.variables.global, &after.variables.global),
+        ].into_iter().flat_map(|(scope, before, after)| variables(scope, before, after)).collect(),
+    }
+}
+
+fn header(before: &Map, after: &Map) -> Vec<Header> {
+    let mut header = Vec::new();
+
+    if before.id != after.id {
+        header.push(Header::Id(Change { before: before.id, after: after.id }));
+    }
+
+    if before.version != after.version {
+        header.push(Header::Version(Change { before: before.version, after: after.version }));
+    }
+
+    if before.filename != after.filename {
+        header.push(Header::Filename(Change { before: before.filename.clone(), after: after.filename.clone() }));
+    }
+
+    if before.flags != after.flags {
+        let sorted = |map: &Map| {
+            let mut flags: Vec<_> = map.flags.iter().cloned().collect();
+            flags.sort();
+            flags
+        };
+
+        header.push(Header::Flags(Change { before: sorted(before), after: sorted(after) }));
+    }
+
+    if before.entrance != after.entrance {
+        header.push(Header::Entrance(Change { before: before.entrance.clone(), after: after.entrance.clone() }));
+    }
+
+    if before.ticks != after.ticks {
+        header.push(Header::Ticks(Change { before: before.ticks, after: after.ticks }));
+    }
+
+    if before.darkness != after.darkness {
+        header.push(Header::Darkness(Change { before: before.darkness, after: after.darkness }));
+    }
+
+    header
+}
+
+fn tiles(before: &Map, after: &Map) -> Vec<Tile> {
+    type Layers = HashMap<(u8, bool, (u8, u8)), u16>;
+
+    fn collect(map: &Map) -> Layers {
+        let mut layers = HashMap::new();
+
+        for group in &map.tiles {
+            for (ceiling, tiles) in [(false, &group.floor), (true, &group.ceiling)] {
+                for tile in tiles {
+                    let position = (tile.position.x.value, tile.position.y.value);
+                    layers.insert((group.elevation.level.value, ceiling, position), tile.id);
+                }
+            }
+        }
+
+        layers
+    }
+
+    let (old, new) = (collect(before), collect(after));
+
+    let mut keys: Vec<_> = old.keys().chain(new.keys()).collect();
+    keys.sort();
+    keys.dedup();
+
+    keys.into_iter().filter_map(|key| {
+        let id = Change { before: old.get(key).copied(), after: new.get(key).copied() };
+        if id.before == id.after { return None; }
+
+        let (elevation, ceiling, position) = *key;
+        Some(Tile { elevation, layer: if ceiling { Layer::Ceiling } else { Layer::Floor }, position, id })
+    }).collect()
+}
+
+fn objects(before: &[prototype::Instance], after: &[prototype::Instance]) -> Vec<Object> {
+    let mut pairs: Vec<(usize, usize)> = Vec::new();
+    let mut unmatched: Vec<usize> = (0..before.len()).collect();
+    let mut added = Vec::new();
+
+    // exact positions first, so moved objects don't steal the matches
+    let mut remaining = Vec::new();
+    for (index, object) in after.iter().enumerate() {
+        match unmatched.iter().position(|o| {
+            before[*o].id == object.id && before[*o].location.grid == object.location.grid
+        }) {
+            Some(found) => pairs.push((unmatched.remove(found), index)),
+            None => remaining.push(index),
+        }
+    }
+
+    for index in remaining {
+        let object = &after[index];
+
+        let elevation = |o: &prototype::Instance| o.location.grid.as_ref().map(|g| g.elevation.level.value);
+        let distance = |o: &prototype::Instance| match (&o.location.grid, &object.location.grid) {
+            (Some(a), Some(b)) => hex::distance(&a.position, &b.position),
+            _ => 0,
+        };
+
+        match unmatched.iter().enumerate()
+            .filter(|(_, o)| before[**o].id == object.id && elevation(&before[**o]) == elevation(object))
+            .min_by_key(|(_, o)| distance(&before[**o]))
+            .map(|(found, _)| found) {
+            Some(found) => pairs.push((unmatched.remove(found), index)),
+            None => added.push(index),
+        }
+    }
+
+    let mut objects: Vec<Object> = unmatched.into_iter().map(Object::Removed).collect();
+    objects.extend(added.into_iter().map(Object::Added));
+
+    pairs.sort();
+    for (old, new) in pairs {
+        let (a, b) = (&before[old], &after[new]);
+        let mut fields = Vec::new();
+
+        if a.location != b.location { fields.push(Field::Location); }
+        if a.patch != b.patch { fields.push(Field::Patch); }
+        if a.appearance != b.appearance { fields.push(Field::Appearance); }
+        if a.inventory != b.inventory { fields.push(Field::Inventory); }
+
+        if !fields.is_empty() {
+            objects.push(Object::Changed { before: old, after: new, fields });
+        }
+    }
+
+    objects
+}
+
+fn scripts(before: &Map, after: &Map) -> Vec<Script> {
+    let mut scripts = Vec::new();
+
+    for script in &before.scripts {
+        match after.scripts.iter().find(|s| s.id == script.id) {
+            None => scripts.push(Script::Removed(script.id)),
+            Some(other) if other != script => scripts.push(Script::Changed(script.id)),
+            Some(_) => {}
+        }
+    }
+
+    for script in &after.scripts {
+        if !before.scripts.iter().any(|s| s.id == script.id) {
+            scripts.push(Script::Added(script.id));
+        }
+    }
+
+    scripts
+}
+
+fn variables(scope: Scope, before: &[i32], after: &[i32]) -> Vec<Variable> {
+    (0..before.len().max(after.len())).filter_map(|index| {
+        let value = Change { before: before.get(index).copied(), after: after.get(index).copied() };
+        if value.before == value.after { return None; }
+
+        Some(Variable { scope, index, value })
+    }).collect()
+}
@@ -0,0 +1,220 @@
+use crate::common::types::geometry::{hex, Coordinate, Scaled};
+use crate::common::types::models::script::Kind;
+use crate::common::types::space::Elevation;
+use crate::formats::pro::ObjectPatch;
+use crate::formats::pro::object::item;
+
+use super::{location, tiles, Map};
+use super::blueprint::{prototype, script};
+
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+pub enum Error {
+    AbsentElevation,
+    PresentElevation,
+    OccupiedElevation,
+    OutOfBounds,
+    MissingLocation,
+    InvalidTile,
+    InvalidScript,
+    DuplicateScript,
+    NotContainer,
+    NotItem,
+}
+
+const SIDE_LEN: u8 = 100;
+
+impl Map {
+    pub fn has_elevation(&self, level: u8) -> bool {
+        self.tiles.iter().any(|group| group.elevation.level.value == level)
+    }
+
+    // Objects are stored elevation by elevation, the same order they are read from a file in.
+    pub fn place(&mut self, instance: prototype::Instance) -> Result<usize, Error> {
+        let level = match &instance.location.grid {
+            None => return Err(Error::MissingLocation),
+            Some(grid) => grid.elevation.level.value,
+        };
+
+        if !self.has_elevation(level) { return Err(Error::AbsentElevation); }
+
+        let index = self.prototypes.iter()
+            .position(|o| o.location.grid.as_ref().is_some_and(|g| g.elevation.level.value > level))
+            .unwrap_or(self.prototypes.len());
+
+        self.prototypes.insert(index, instance);
+        Ok(index)
+    }
+
+    pub fn relocate(&mut self, index: usize, grid: location::Grid) -> Result<usize, Error> {
+        if index >= self.prototypes.len() { return Err(Error::OutOfBounds); }
+        if !self.has_elevation(grid.elevation.level.value) { return Err(Error::AbsentElevation); }
+
+        let mut instance = self.prototypes.remove(index);
+        instance.location.grid = Some(grid);
+
+        self.place(instance)
+    }
+
+    // Scripts owned by the object or by items in its inventory are dropped along with it.
+    pub fn remove(&mut self, index: usize) -> Result<prototype::Instance, Error> {
+        if index >= self.prototypes.len() { return Err(Error::OutOfBounds); }
+
+        let instance = self.prototypes.remove(index);
+        self.disown(&[&instance]);
+
+        Ok(instance)
+    }
+
+    fn disown(&mut self, instances: &[&prototype::Instance]) {
+        fn owners(instance: &prototype::Instance, ids: &mut Vec<u32>) {
+            ids.push(instance.connections.object_id);
+            for item in instance.inventory.items.iter().flatten() { owners(item, ids); }
+        }
+
+        let mut ids = Vec::new();
+        for instance in instances { owners(instance, &mut ids); }
+
+        self.scripts.retain(|s| !matches!(s.connections.object_id, Some(id) if ids.contains(&id)));
+    }
+
+    // Zero or one ids mean an empty tile, thus `None` clears it.
+    pub fn set_tile(&mut self, level: u8, layer: tiles::Layer, position: (u8, u8), id: Option<u16>) -> Result<(), Error> {
+        if position.0 >= SIDE_LEN || position.1 >= SIDE_LEN { return Err(Error::OutOfBounds); }
+        if id.is_some_and(|id| id <= 1) { return Err(Error::InvalidTile); }
+
+        let group = self.tiles.iter_mut()
+            .find(|group| group.elevation.level.value == level)
+            .ok_or(Error::AbsentElevation)?;
+
+        let list = match layer {
+            tiles::Layer::Floor => &mut group.floor,
+            tiles::Layer::Ceiling => &mut group.ceiling,
+        };
+
+        list.retain(|tile| (tile.position.x.value, tile.position.y.value) != position);
+
+        if let Some(id) = id {
+            // rows go top to bottom, columns right to left
+            let order = |x: u8, y: u8| (y, SIDE_LEN - 1 - x);
+            let index = list.iter()
+                .position(|tile| order(tile.position.x.value, tile.position.y.value) > order(position.0, position.1))
+                .unwrap_or(list.len());
+
+            list.insert(index, tiles::Instance {
+                id,
+                position: Coordinate {
+                    x: Scaled { value: position.0, scale: u8::MIN..SIDE_LEN },
+                    y: Scaled { value: position.1, scale: u8::MIN..SIDE_LEN },
+                },
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn add_script(&mut self, instance: script::Instance) -> Result<(), Error> {
+        match &instance.kind {
+            Kind::Spatial(spatial) => {
+                let position = &spatial.position;
+
+                if !hex::is_valid(position) {
+                    return Err(Error::OutOfBounds);
+                }
+
+                if !self.has_elevation(spatial.elevation.level.value) { return Err(Error::AbsentElevation); }
+            }
+            Kind::Timed(_) => {}
+            _ => return Err(Error::InvalidScript),
+        }
+
+        if self.scripts.iter().any(|s| s.id == instance.id) { return Err(Error::DuplicateScript); }
+
+        // scripts are grouped by kind, in the order of the file
+        fn rank(kind: &Kind<(), script::spatial::Instance, script::time::Instance, (), ()>) -> u8 {
+            match kind {
+                Kind::System(_) => 0,
+                Kind::Spatial(_) => 1,
+                Kind::Timed(_) => 2,
+                Kind::Item(_) => 3,
+                Kind::Critter(_) => 4,
+            }
+        }
+
+        let index = self.scripts.iter()
+            .position(|s| rank(&s.kind) > rank(&instance.kind))
+            .unwrap_or(self.scripts.len());
+
+        self.scripts.insert(index, instance);
+        Ok(())
+    }
+
+    pub fn resize_variables(&mut self, local: usize, global: usize) {
+        self.variables.local.resize(local, 0);
+        self.variables.global.resize(global, 0);
+    }
+
+    pub fn add_elevation(&mut self, level: u8) -> Result<(), Error> {
+        let elevation = Elevation::try_from(level as u32).map_err(|_| Error::OutOfBounds)?;
+        if self.has_elevation(level) { return Err(Error::PresentElevation); }
+
+        let index = self.tiles.iter()
+            .position(|group| group.elevation.level.value > level)
+            .unwrap_or(self.tiles.len());
+
+        self.tiles.insert(index, tiles::Group { floor: Vec::new(), ceiling: Vec::new(), elevation });
+        Ok(())
+    }
+
+    // Drops objects, their scripts and spatial scripts of the elevation along with its tiles.
+    pub fn remove_elevation(&mut self, level: u8) -> Result<(), Error> {
+        if !self.has_elevation(level) { return Err(Error::AbsentElevation); }
+        if self.entrance.elevation.level.value == level { return Err(Error::OccupiedElevation); }
+
+        self.tiles.retain(|group| group.elevation.level.value != level);
+
+        let (removed, kept) = std::mem::take(&mut self.prototypes).into_iter().partition::<Vec<_>, _>(|o| {
+            o.location.grid.as_ref().is_some_and(|g| g.elevation.level.value == level)
+        });
+
+        self.prototypes = kept;
+        self.disown(&removed.iter().collect::<Vec<_>>());
+
+        self.scripts.retain(|s| match &s.kind {
+            Kind::Spatial(spatial) => spatial.elevation.level.value != level,
+            _ => true,
+        });
+
+        Ok(())
+    }
+
+    // Puts an item into the first free slot of a critter or a container, growing it if full.
+    pub fn stow(&mut self, owner: usize, mut item: prototype::Instance) -> Result<usize, Error> {
+        if !matches!(item.patch.object, ObjectPatch::Item(_)) { return Err(Error::NotItem); }
+
+        let owner = self.prototypes.get_mut(owner).ok_or(Error::OutOfBounds)?;
+
+        match &owner.patch.object {
+            ObjectPatch::Critter(_) | ObjectPatch::Item(item::Type::Container(_)) => {}
+            _ => return Err(Error::NotContainer),
+        }
+
+        item.location.grid = None;
+
+        let inventory = &mut owner.inventory;
+
+        let slot = match inventory.items.iter().position(|slot| slot.is_none()) {
+            Some(slot) => {
+                inventory.items[slot] = Some(item);
+                slot
+            }
+            None => {
+                inventory.items.push(Some(item));
+                inventory.items.len() - 1
+            }
+        };
+
+        inventory.capacity = inventory.capacity.max(inventory.items.len() as u32);
+
+        Ok(slot)
+    }
+}
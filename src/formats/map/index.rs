@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+
+use crate::common::types::geometry::hex;
+use crate::common::types::models::Identifier;
+use crate::common::types::space::Elevation;
+use crate::formats::pro::ObjectType;
+
+use super::Map;
+use super::blueprint::prototype::Instance;
+
+pub struct Entry<'a> {
+    pub instance: &'a Instance,
+    pub owner: Option<&'a Instance>,
+}
+
+pub struct Index<'a> {
+    entries: Vec<Entry<'a>>,
+    grid: HashMap<(u8, hex::Hex), Vec<usize>>,
+    types: HashMap<ObjectType, Vec<usize>>,
+    identifiers: HashMap<Identifier<ObjectType>, Vec<usize>>,
+}
+
+impl<'a> Index<'a> {
+    pub fn new(map: &'a Map) -> Self {
+        let mut index = Self {
+            entries: Vec::new(),
+            grid: HashMap::new(),
+            types: HashMap::new(),
+            identifiers: HashMap::new(),
+        };
+
+        for instance in &map.prototypes {
+            if let Some(grid) = &instance.location.grid {
+                let key = (grid.elevation.level.value, grid.position.clone());
+                index.grid.entry(key).or_default().push(index.entries.len());
+            }
+
+            index.insert(instance, None);
+        }
+
+        index
+    }
+
+    fn insert(&mut self, instance: &'a Instance, owner: Option<&'a Instance>) {
+        let position = self.entries.len();
+
+        self.types.entry(instance.id.kind.clone()).or_default().push(position);
+        self.identifiers.entry(instance.id.clone()).or_default().push(position);
+        self.entries.push(Entry { instance, owner });
+
+        for item in instance.inventory.items.iter().flatten() {
+            self.insert(item, Some(instance));
+        }
+    }
+
+    fn collect(&self, positions: Option<&Vec<usize>>) -> Vec<&Entry<'a>> {
+        positions.map_or(Vec::new(), |p| p.iter().map(|i| &self.entries[*i]).collect())
+    }
+
+    pub fn entries(&self) -> &[Entry<'a>] {
+        &self.entries
+    }
+
+    pub fn at(&self, elevation: &Elevation, hex: &hex::Hex) -> Vec<&Entry<'a>> {
+        self.collect(self.grid.get(&(elevation.level.value, hex.clone())))
+    }
+
+    pub fn within(&self, elevation: &Elevation, center: &hex::Hex, radius: u32) -> Vec<&Entry<'a>> {
+        hex::area(center, radius).iter().flat_map(|hex| self.at(elevation, hex)).collect()
+    }
+
+    pub fn by_type(&self, kind: &ObjectType) -> Vec<&Entry<'a>> {
+        self.collect(self.types.get(kind))
+    }
+
+    pub fn by_identifier(&self, identifier: &Identifier<ObjectType>) -> Vec<&Entry<'a>> {
+        self.collect(self.identifiers.get(identifier))
+    }
+}
@@ -0,0 +1,139 @@
+use crate::common::types::geometry::hex;
+use crate::common::types::models::script::Kind;
+use crate::formats::pro::{ObjectInstance, ObjectPatch};
+use crate::formats::pro::meta::info::flags::Root;
+use crate::formats::pro::object::{item, scenery};
+
+use super::Map;
+use super::blueprint::{prototype, script};
+use super::parse::Provider;
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Hash)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum Check {
+    ScriptOutOfGrid,
+    ScriptOnAbsentElevation,
+    InventoryOverflow { capacity: u32, count: usize },
+    InventoryInNonContainer,
+    // a heuristic over the whole map: locked doors are reported when no key with a code lies anywhere on it,
+    // doors opened by scripts or by keys brought from elsewhere show up too, hence only a warning
+    LockedDoorWithoutKeys,
+    MissingLocation,
+    MissingPrototype,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reference<'a> {
+    Object(&'a prototype::Instance),
+    Script(&'a script::Instance),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic<'a> {
+    pub severity: Severity,
+    pub check: Check,
+    pub reference: Reference<'a>,
+}
+
+pub fn lint<'a, P: Provider>(map: &'a Map, provider: &P) -> Vec<Diagnostic<'a>> {
+    let mut diagnostics = Vec::new();
+
+    for script in &map.scripts {
+        if let Kind::Spatial(spatial) = &script.kind {
+            let position = &spatial.position;
+
+            if !hex::is_valid(position) {
+                diagnostics.push(Diagnostic {
+                    severity: Severity::Error,
+                    check: Check::ScriptOutOfGrid,
+                    reference: Reference::Script(script),
+                });
+            }
+
+            if !map.tiles.iter().any(|group| group.elevation == spatial.elevation) {
+                diagnostics.push(Diagnostic {
+                    severity: Severity::Error,
+                    check: Check::ScriptOnAbsentElevation,
+                    reference: Reference::Script(script),
+                });
+            }
+        }
+    }
+
+    let mut keys = false;
+    let mut doors = Vec::new();
+
+    fn object<'a, P: Provider>(
+        instance: &'a prototype::Instance,
+        nested: bool,
+        provider: &P,
+        keys: &mut bool,
+        doors: &mut Vec<&'a prototype::Instance>,
+        diagnostics: &mut Vec<Diagnostic<'a>>,
+    ) {
+        let mut report = |severity, check| diagnostics.push(Diagnostic {
+            severity,
+            check,
+            reference: Reference::Object(instance),
+        });
+
+        if !nested && instance.location.grid.is_none() {
+            report(Severity::Warning, Check::MissingLocation);
+        }
+
+        let inventory = &instance.inventory;
+        let count = inventory.items.iter().flatten().count();
+
+        if inventory.items.len() > inventory.capacity as usize {
+            report(Severity::Warning, Check::InventoryOverflow { capacity: inventory.capacity, count });
+        }
+
+        match provider.provide(&instance.id) {
+            Err(_) => report(Severity::Error, Check::MissingPrototype),
+            Ok(prototype) => {
+                let container = match &prototype.object {
+                    ObjectInstance::Critter(_) => true,
+                    ObjectInstance::Item(item) => matches!(item.r#type, item::Type::Container(_)),
+                    _ => false,
+                };
+
+                if count > 0 && !container {
+                    report(Severity::Warning, Check::InventoryInNonContainer);
+                }
+            }
+        }
+
+        match &instance.patch.object {
+            ObjectPatch::Item(item::Type::Key(key)) if key.code.is_some() => *keys = true,
+            ObjectPatch::Scenery(scenery::Patch::Door(_)) if instance.patch.meta.flags.contains(&Root::Locked) => {
+                doors.push(instance)
+            }
+            _ => {}
+        }
+
+        for item in inventory.items.iter().flatten() {
+            object(item, true, provider, keys, doors, diagnostics);
+        }
+    }
+
+    for instance in &map.prototypes {
+        object(instance, false, provider, &mut keys, &mut doors, &mut diagnostics);
+    }
+
+    if !keys {
+        for door in doors {
+            diagnostics.push(Diagnostic {
+                severity: Severity::Warning,
+                check: Check::LockedDoorWithoutKeys,
+                reference: Reference::Object(door),
+            });
+        }
+    }
+
+    diagnostics
+}
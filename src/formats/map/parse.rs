@@ -1,4 +1,5 @@
 use std::io::{Read, Seek, SeekFrom};
+use std::sync::Arc;
 
 use byteorder::{BigEndian, ReadBytesExt};
 
@@ -14,18 +15,17 @@ mod flags;
 mod entrance;
 mod variables;
 mod tiles;
-mod prototypes;
+pub(crate) mod prototypes;
 mod scripts;
 
 pub trait Provider {
-    fn provide(&self, identifier: &Identifier<ObjectType>) -> Result<pro::Prototype, errors::Error>;
+    fn provide(&self, identifier: &Identifier<ObjectType>) -> Result<Arc<pro::Prototype>, errors::Error>;
 }
 
 pub fn map<S: Read + Seek, P: Provider>(source: &mut S, provider: &P) -> Result<Map, errors::Error> {
     source.seek(SeekFrom::Start(0))?;
 
-    let version = source.read_u32::<BigEndian>()?;
-    let read_ladders_map = version == 20; // Fallout™ 2 maps
+    let version = Version::try_from(source.read_u32::<BigEndian>()?)?;
 
     let mut filename_bytes = [0u8; 16];
     source.read_exact(&mut filename_bytes)?;
@@ -49,7 +49,7 @@ pub fn map<S: Read + Seek, P: Provider>(source: &mut S, provider: &P) -> Result<
 
     let tiles = tiles::list(source, &elevations)?;
     let scripts = scripts::list(source)?;
-    let prototypes = prototypes::list(source, provider, &elevations, read_ladders_map)?;
+    let prototypes = prototypes::list(source, provider, &elevations, &version)?;
 
     Ok(Map {
         id,
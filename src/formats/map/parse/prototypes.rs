@@ -1,16 +1,16 @@
 use super::*;
 
-mod prototype;
+pub(crate) mod prototype;
 
 pub fn list<S: Read + Seek, P: Provider>
-(source: &mut S, provider: &P, elevations: &[Option<()>], read_ladders_map: bool) ->
+(source: &mut S, provider: &P, elevations: &[Option<()>], version: &Version) ->
 Result<Vec<blueprint::prototype::Instance>, errors::Error> {
     let mut list = Vec::new();
     let count = source.read_u32::<BigEndian>()?;
 
     for _ in elevations {
         for _ in 0..source.read_u32::<BigEndian>()? {
-            list.push(prototype::instance(source, provider, read_ladders_map)?);
+            list.push(prototype::instance(source, provider, version)?);
         }
     }
 
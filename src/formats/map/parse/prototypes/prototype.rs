@@ -11,15 +11,16 @@ use crate::formats::map::blueprint::prototype;
 use crate::formats::map::blueprint::prototype::Appearance;
 use crate::formats::map::location::{Grid, Screen};
 use crate::formats::map::parse::{errors, Provider};
+use crate::common::types::version::Version;
 use crate::formats::pro;
 use crate::formats::pro::meta;
 use crate::formats::pro::meta::info::Light;
 
 mod patch;
 
-pub fn instance<S: Read + Seek, P: Provider>(source: &mut S, provider: &P, read_ladders_map: bool) ->
+pub fn instance<S: Read + Seek, P: Provider>(source: &mut S, provider: &P, version: &Version) ->
 Result<prototype::Instance, errors::Error> {
-    let _entry_id = source.read_u32::<BigEndian>()?;
+    let object_id = source.read_u32::<BigEndian>()?;
 
     let position = u32::try_from(source.read_i32::<BigEndian>()?)
         .ok()
@@ -67,7 +68,7 @@ Result<prototype::Instance, errors::Error> {
     source.seek(SeekFrom::Current(4))?;
 
     flags.extend(pro::parse::flags::extended(source)?);
-    let patch = patch::instance(source, provider, &identifier, read_ladders_map)?;
+    let patch = patch::instance(source, provider, &identifier, version)?;
 
     let mut inventory = Vec::new();
     for _ in u32::MIN..inventory_items_capacity { inventory.push(None) }
@@ -82,7 +83,7 @@ Result<prototype::Instance, errors::Error> {
         for _ in usize::MIN..overhead { inventory.push(None) }
 
         // Now this operation is safe from panic
-        inventory[index] = Some(instance(source, provider, read_ladders_map)?);
+        inventory[index] = Some(instance(source, provider, version)?);
     }
 
     Ok(prototype::Instance {
@@ -101,6 +102,7 @@ Result<prototype::Instance, errors::Error> {
             } else { None },
             sprite,
         },
-        inventory,
+        inventory: prototype::inventory::Instance { capacity: inventory_items_capacity, items: inventory },
+        connections: prototype::Connections { object_id },
     })
 }
\ No newline at end of file
@@ -8,13 +8,13 @@ mod scenery;
 mod misc;
 
 pub(crate) fn instance<S: Read, P: Provider>
-(source: &mut S, provider: &P, identifier: &Identifier<ObjectType>, read_ladders_map: bool) -> Result<ObjectPatch, errors::Error> {
+(source: &mut S, provider: &P, identifier: &Identifier<ObjectType>, version: &Version) -> Result<ObjectPatch, errors::Error> {
     let prototype = provider.provide(&identifier)?;
 
     Ok(match &prototype.object {
         ObjectInstance::Item(item) => { ObjectPatch::Item(item::patch(source, item)?) }
         ObjectInstance::Critter(_) => { ObjectPatch::Critter(critter::patch(source)?) }
-        ObjectInstance::Scenery(scenery) => { ObjectPatch::Scenery(scenery::patch(source, scenery, read_ladders_map)?) }
+        ObjectInstance::Scenery(scenery) => { ObjectPatch::Scenery(scenery::patch(source, scenery, version)?) }
         ObjectInstance::Wall(_) => { ObjectPatch::Wall(()) }
         ObjectInstance::Tile(_) => { ObjectPatch::Tile(()) }
         ObjectInstance::Misc(_) => { ObjectPatch::Misc(misc::patch(source, &identifier.index)?) }
@@ -1,6 +1,7 @@
 use std::io::Read;
 
 use crate::common::types::errors;
+use crate::common::types::version::Version;
 use crate::formats::pro::object::scenery::{Body, Instance, Patch};
 
 mod door;
@@ -8,12 +9,12 @@ mod stairs;
 mod elevator;
 mod ladder;
 
-pub(crate) fn patch<S: Read>(source: &mut S, scenery: &Instance, read_ladders_map: bool) -> Result<Patch, errors::Error> {
+pub(crate) fn patch<S: Read>(source: &mut S, scenery: &Instance, version: &Version) -> Result<Patch, errors::Error> {
     Ok(match &scenery.body {
         Body::Door(_) => { Patch::Door(door::patch(source)?) }
         Body::Stairs(_) => { Patch::Stairs(stairs::patch(source)?) }
         Body::Elevator(_) => { Patch::Elevator(elevator::patch(source)?) }
-        Body::Ladder(_) => { Patch::Ladder(ladder::patch(source, read_ladders_map)?) }
+        Body::Ladder(_) => { Patch::Ladder(ladder::patch(source, version)?) }
         Body::Generic(_) => { Patch::Generic(()) }
     })
 }
\ No newline at end of file
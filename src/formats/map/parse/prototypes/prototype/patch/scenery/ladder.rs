@@ -4,14 +4,16 @@ use byteorder::{BigEndian, ReadBytesExt};
 
 use crate::common::traits::TryFromOptional;
 use crate::common::types::errors;
+use crate::common::types::version::Version;
 use crate::formats::pro::object::common::map;
 use crate::formats::pro::object::common::map::Map;
 use crate::formats::pro::object::scenery::ladder::Patch;
 
-pub(crate) fn patch<S: Read>(source: &mut S, read_map: bool) -> Result<Patch, errors::Error> {
-    let map = if read_map {
-        Map::try_from_optional(source.read_i32::<BigEndian>()?, -2)?
-    } else { None };
+pub(crate) fn patch<S: Read>(source: &mut S, version: &Version) -> Result<Patch, errors::Error> {
+    let map = match version {
+        Version::First => None,
+        Version::Second => Map::try_from_optional(source.read_i32::<BigEndian>()?, -2)?,
+    };
 
     let mut destination_bytes = [0u8; 4];
     source.read_exact(&mut destination_bytes)?;
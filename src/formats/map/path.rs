@@ -0,0 +1,112 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use crate::common::types::geometry::hex;
+use crate::common::types::space::Elevation;
+use crate::formats::pro::{ObjectPatch, Type};
+use crate::formats::pro::meta::info::flags::Root;
+use crate::formats::pro::object::scenery;
+
+use super::{location, Map};
+use super::blueprint::prototype;
+
+pub(crate) fn blocks(instance: &prototype::Instance) -> bool {
+    let flags = &instance.patch.meta.flags;
+
+    if flags.contains(&Root::NotBlocking) { return false; }
+
+    if let ObjectPatch::Scenery(scenery::Patch::Door(door)) = &instance.patch.object {
+        if door.flags.contains(&scenery::door::Flag::Passable) { return false; }
+    }
+
+    matches!(instance.id.kind, Type::Critter(_) | Type::Scenery(_) | Type::Wall(_))
+}
+
+// Hexes taken by an object on the grid, multi-hex objects cover neighbours of their position as well.
+pub fn footprint(instance: &prototype::Instance) -> Vec<hex::Hex> {
+    let grid = match &instance.location.grid {
+        Some(grid) => grid,
+        None => return Vec::new(),
+    };
+
+    let mut footprint = vec![grid.position.clone()];
+
+    if instance.patch.meta.flags.contains(&Root::MultiHex) {
+        footprint.extend(hex::neighbours(&grid.position));
+    }
+
+    footprint
+}
+
+// Footprints of objects standing at the hex, these never stop moves or shots from or to it.
+pub(crate) fn occupied(map: &Map, elevation: &Elevation, position: &hex::Hex) -> HashSet<hex::Hex> {
+    let mut occupied = HashSet::from([position.clone()]);
+
+    for instance in &map.prototypes {
+        if matches!(&instance.location.grid, Some(grid) if &grid.elevation == elevation && &grid.position == position) {
+            occupied.extend(footprint(instance));
+        }
+    }
+
+    occupied
+}
+
+pub(crate) fn footprints<F: Fn(&prototype::Instance) -> bool>(map: &Map, elevation: &Elevation, blocks: F) ->
+HashSet<hex::Hex> {
+    map.prototypes.iter()
+        .filter(|instance| matches!(&instance.location.grid, Some(grid) if &grid.elevation == elevation))
+        .filter(|instance| blocks(instance))
+        .flat_map(footprint)
+        .collect()
+}
+
+pub fn obstacles(map: &Map, elevation: &Elevation) -> HashSet<hex::Hex> {
+    footprints(map, elevation, blocks)
+}
+
+pub fn find(map: &Map, from: &location::Grid, to: &location::Grid) -> Option<Vec<hex::Hex>> {
+    if from.elevation != to.elevation { return None; }
+
+    let obstacles = obstacles(map, &from.elevation);
+    let (start, goal) = (&from.position, &to.position);
+
+    let mut exempt = occupied(map, &from.elevation, start);
+    exempt.extend(occupied(map, &to.elevation, goal));
+
+    let mut queue = BinaryHeap::new();
+    let mut costs = HashMap::new();
+    let mut previous: HashMap<hex::Hex, hex::Hex> = HashMap::new();
+
+    // heap is keyed by hex numbers, as coordinates have no ordering
+    costs.insert(start.clone(), 0u32);
+    queue.push(Reverse((hex::distance(start, goal), u32::from(start))));
+
+    while let Some(Reverse((_, number))) = queue.pop() {
+        let current = hex::Hex::try_from(number).ok()?;
+
+        if &current == goal {
+            let mut path = vec![current];
+
+            while let Some(hex) = previous.get(path.last()?) {
+                path.push(hex.clone());
+            }
+
+            path.reverse();
+            return Some(path);
+        }
+
+        let cost = costs[&current] + 1;
+
+        for neighbour in hex::neighbours(&current) {
+            if !exempt.contains(&neighbour) && obstacles.contains(&neighbour) { continue; }
+            if matches!(costs.get(&neighbour), Some(known) if *known <= cost) { continue; }
+
+            costs.insert(neighbour.clone(), cost);
+            previous.insert(neighbour.clone(), current.clone());
+
+            queue.push(Reverse((cost + hex::distance(&neighbour, goal), u32::from(&neighbour))));
+        }
+    }
+
+    None
+}
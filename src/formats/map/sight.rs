@@ -0,0 +1,51 @@
+use std::collections::HashSet;
+
+use crate::common::types::geometry::hex;
+use crate::common::types::space::Elevation;
+use crate::formats::pro::Type;
+use crate::formats::pro::meta::info::flags::Root;
+
+use super::{path, Map};
+use super::blueprint::prototype;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Query {
+    Vision,
+    Projectile,
+}
+
+fn blocks(instance: &prototype::Instance, query: &Query) -> bool {
+    let flags = &instance.patch.meta.flags;
+
+    match query {
+        Query::Vision => {
+            !flags.contains(&Root::LightThrough) &&
+                matches!(instance.id.kind, Type::Scenery(_) | Type::Wall(_))
+        }
+        Query::Projectile => {
+            !flags.contains(&Root::ShotThrough) &&
+                matches!(instance.id.kind, Type::Critter(_) | Type::Scenery(_) | Type::Wall(_))
+        }
+    }
+}
+
+fn obstacles(map: &Map, elevation: &Elevation, query: &Query) -> HashSet<hex::Hex> {
+    path::footprints(map, elevation, |instance| blocks(instance, query))
+}
+
+// First hex between the two (exclusive) which stops the query, if any.
+// Footprints of objects standing at both ends, e.g. a multi-hex shooter, don't stop it.
+pub fn obstacle(map: &Map, elevation: &Elevation, from: &hex::Hex, to: &hex::Hex, query: &Query) -> Option<hex::Hex> {
+    let obstacles = obstacles(map, elevation, query);
+
+    let mut exempt = path::occupied(map, elevation, from);
+    exempt.extend(path::occupied(map, elevation, to));
+
+    hex::line(from, to).into_iter()
+        .filter(|hex| !exempt.contains(hex))
+        .find(|hex| obstacles.contains(hex))
+}
+
+pub fn passes(map: &Map, elevation: &Elevation, from: &hex::Hex, to: &hex::Hex, query: &Query) -> bool {
+    obstacle(map, elevation, from, to, query).is_none()
+}
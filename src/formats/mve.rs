@@ -0,0 +1,28 @@
+use crate::formats::{acm, frm, pal};
+
+pub mod parse;
+pub mod decode;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Video {
+    pub width: u16,
+    pub height: u16,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Audio {
+    pub channels: u8,
+    pub rate: u16,
+    pub bits: u8,
+    pub compressed: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Event {
+    Palette(Box<pal::Palette>),
+    Frame(frm::Frame),
+    Audio(Vec<acm::Sample>),
+}
@@ -0,0 +1,218 @@
+use std::io::{Cursor, ErrorKind, Read};
+use std::time::Duration;
+
+use byteorder::{LittleEndian, ReadBytesExt};
+
+use crate::common::graphics::Pixel;
+use crate::common::types::errors::Error;
+use crate::common::types::geometry::Scaled;
+use crate::formats::{frm, pal};
+
+use super::{Audio, Event, Video};
+
+mod video;
+mod audio;
+
+pub struct Decoder<S> {
+    pub timer: Option<Duration>,
+    pub video: Option<Video>,
+    pub audio: Option<Audio>,
+
+    source: S,
+    remaining: usize,
+    finished: bool,
+
+    palette: [u8; 768],
+    map: Vec<u8>,
+    buffers: Option<video::Buffers>,
+}
+
+impl<S: Read> Decoder<S> {
+    pub(crate) fn new(source: S) -> Self {
+        Self {
+            timer: None,
+            video: None,
+            audio: None,
+            source,
+            remaining: 0,
+            finished: false,
+            palette: [0; 768],
+            map: Vec::new(),
+            buffers: None,
+        }
+    }
+
+    fn opcode(&mut self) -> Result<Option<Event>, Error> {
+        if self.remaining == 0 {
+            let size = match self.source.read_u16::<LittleEndian>() {
+                Ok(value) => value,
+                Err(error) if error.kind() == ErrorKind::UnexpectedEof => {
+                    self.finished = true;
+                    return Ok(None);
+                }
+                Err(error) => return Err(Error::IO(error)),
+            };
+
+            let _type = self.source.read_u16::<LittleEndian>()?;
+            self.remaining = size as usize;
+
+            return Ok(None);
+        }
+
+        let length = self.source.read_u16::<LittleEndian>()? as usize;
+        let kind = self.source.read_u8()?;
+        let version = self.source.read_u8()?;
+
+        self.remaining = self.remaining.checked_sub(4 + length).ok_or(Error::Format)?;
+
+        let mut bytes = vec![0u8; length];
+        self.source.read_exact(&mut bytes)?;
+
+        let mut data = Cursor::new(bytes.as_slice());
+
+        Ok(match kind {
+            0x00 => {
+                self.finished = true;
+                None
+            }
+            0x02 => {
+                let rate = data.read_u32::<LittleEndian>()?;
+                let subdivision = data.read_u16::<LittleEndian>()?;
+
+                self.timer = Some(Duration::from_micros(rate as u64 * subdivision as u64));
+                None
+            }
+            0x03 => {
+                let _unknown = data.read_u16::<LittleEndian>()?;
+                let flags = data.read_u16::<LittleEndian>()?;
+                let rate = data.read_u16::<LittleEndian>()?;
+
+                self.audio = Some(Audio {
+                    channels: (flags & 0x01) as u8 + 1,
+                    rate,
+                    bits: (((flags >> 1) & 0x01) as u8 + 1) * 8,
+                    compressed: version == 1 && (flags & 0x04) != 0,
+                });
+
+                None
+            }
+            0x05 => {
+                let width = data.read_u16::<LittleEndian>()?;
+                let height = data.read_u16::<LittleEndian>()?;
+
+                if version >= 2 {
+                    let _count = data.read_u16::<LittleEndian>()?;
+                    if data.read_u16::<LittleEndian>()? != 0 { return Err(Error::Format); }
+                }
+
+                let video = Video {
+                    width: width.checked_mul(8).ok_or(Error::Format)?,
+                    height: height.checked_mul(8).ok_or(Error::Format)?,
+                };
+
+                self.buffers = Some(video::Buffers::new(&video));
+                self.video = Some(video);
+
+                None
+            }
+            0x07 => {
+                let buffers = self.buffers.as_ref().ok_or(Error::Format)?;
+
+                Some(Event::Frame(frm::Frame {
+                    size: frm::Size { width: buffers.width as u16, height: buffers.height as u16 },
+                    shift: frm::Shift { x: 0, y: 0 },
+                    indexes: buffers.last.clone(),
+                }))
+            }
+            0x08 | 0x09 => {
+                let _sequence = data.read_u16::<LittleEndian>()?;
+                let mask = data.read_u16::<LittleEndian>()?;
+                let length = data.read_u16::<LittleEndian>()? as usize;
+
+                if mask & 0x01 == 0 { return Ok(None); }
+
+                let audio = self.audio.as_ref().ok_or(Error::Format)?;
+
+                Some(Event::Audio(if kind == 0x09 {
+                    vec![0; length / (audio.bits / 8) as usize]
+                } else {
+                    audio::samples(&mut data, audio, length)?
+                }))
+            }
+            0x0C => {
+                let start = data.read_u16::<LittleEndian>()? as usize;
+                let count = data.read_u16::<LittleEndian>()? as usize;
+
+                let range = start * 3..(start + count) * 3;
+                data.read_exact(self.palette.get_mut(range).ok_or(Error::Format)?)?;
+
+                Some(Event::Palette(Box::new(self.palette())))
+            }
+            0x0D => {
+                for group in 0..32 {
+                    let mask = data.read_u8()?;
+
+                    for index in 0..8 {
+                        if mask & (1 << index) != 0 {
+                            let offset = (group * 8 + index) * 3;
+                            data.read_exact(&mut self.palette[offset..offset + 3])?;
+                        }
+                    }
+                }
+
+                Some(Event::Palette(Box::new(self.palette())))
+            }
+            0x0F => {
+                self.map = bytes.clone();
+                None
+            }
+            0x11 => {
+                let buffers = self.buffers.as_mut().ok_or(Error::Format)?;
+
+                data.set_position(14);
+                video::frame(&mut data, &self.map, buffers)?;
+
+                None
+            }
+            _ => None
+        })
+    }
+
+    fn palette(&self) -> pal::Palette {
+        let scale = u8::MIN..=63;
+        let mut colors = [(); 256].map(|_| None);
+
+        for (index, color) in colors.iter_mut().enumerate() {
+            let rgb = &self.palette[index * 3..index * 3 + 3];
+
+            if rgb.iter().all(|c| scale.contains(c)) {
+                *color = Some(Pixel {
+                    red: Scaled { value: rgb[0], scale: scale.clone() },
+                    green: Scaled { value: rgb[1], scale: scale.clone() },
+                    blue: Scaled { value: rgb[2], scale: scale.clone() },
+                });
+            }
+        }
+
+        pal::Palette { colors }
+    }
+}
+
+impl<S: Read> Iterator for Decoder<S> {
+    type Item = Result<Event, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.finished {
+            match self.opcode() {
+                Ok(None) => continue,
+                Ok(Some(event)) => return Some(Ok(event)),
+                Err(error) => {
+                    self.finished = true;
+                    return Some(Err(error));
+                }
+            }
+        }
+
+        None
+    }
+}
@@ -0,0 +1,331 @@
+use std::io::Read;
+
+use byteorder::{LittleEndian, ReadBytesExt};
+
+use crate::common::types::errors::Error;
+use crate::formats::mve::Video;
+
+pub(crate) struct Buffers {
+    pub(crate) width: usize,
+    pub(crate) height: usize,
+
+    pub(crate) current: Vec<u8>,
+    pub(crate) last: Vec<u8>,
+    pub(crate) second: Vec<u8>,
+}
+
+impl Buffers {
+    pub(crate) fn new(video: &Video) -> Self {
+        let (width, height) = (video.width as usize, video.height as usize);
+
+        Self {
+            width,
+            height,
+            current: vec![0; width * height],
+            last: vec![0; width * height],
+            second: vec![0; width * height],
+        }
+    }
+
+    fn rotate(&mut self) {
+        std::mem::swap(&mut self.second, &mut self.last);
+        std::mem::swap(&mut self.last, &mut self.current);
+    }
+}
+
+enum Source {
+    Current,
+    Last,
+    Second,
+}
+
+struct Block<'a> {
+    buffers: &'a mut Buffers,
+    x: usize,
+    y: usize,
+}
+
+impl Block<'_> {
+    fn copy(&mut self, source: Source, dx: i32, dy: i32) -> Result<(), Error> {
+        let width = self.buffers.width;
+        let offset = (self.y * width + self.x) as i64 + dy as i64 * width as i64 + dx as i64;
+        let limit = ((self.buffers.height - 8) * width + width - 8) as i64;
+
+        if !(0..=limit).contains(&offset) { return Err(Error::Format); }
+
+        let offset = offset as usize;
+        for row in 0..8 {
+            let from = offset + row * width;
+            let to = (self.y + row) * width + self.x;
+
+            match source {
+                Source::Current => self.buffers.current.copy_within(from..from + 8, to),
+                Source::Last => self.buffers.current[to..to + 8].copy_from_slice(&self.buffers.last[from..from + 8]),
+                Source::Second => self.buffers.current[to..to + 8].copy_from_slice(&self.buffers.second[from..from + 8]),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn fill(&mut self, pixels: &[u8; 64]) {
+        let width = self.buffers.width;
+
+        for row in 0..8 {
+            let to = (self.y + row) * width + self.x;
+            self.buffers.current[to..to + 8].copy_from_slice(&pixels[row * 8..row * 8 + 8]);
+        }
+    }
+}
+
+fn motion(byte: u8) -> (i32, i32) {
+    let byte = byte as i32;
+
+    if byte < 56 {
+        (8 + byte % 7, byte / 7)
+    } else {
+        (-14 + (byte - 56) % 29, 8 + (byte - 56) / 29)
+    }
+}
+
+fn colors<S: Read, const N: usize>(source: &mut S) -> Result<[u8; N], Error> {
+    let mut colors = [0u8; N];
+    source.read_exact(&mut colors)?;
+
+    Ok(colors)
+}
+
+fn quadrant(index: usize) -> (usize, usize) {
+    (index / 2 * 4, index % 2 * 4)
+}
+
+fn pixels<S: Read>(source: &mut S, opcode: u8) -> Result<[u8; 64], Error> {
+    let mut pixels = [0u8; 64];
+    let mut set = |x: usize, y: usize, color: u8| pixels[y * 8 + x] = color;
+
+    match opcode {
+        0x7 => {
+            let p = colors::<_, 2>(source)?;
+
+            if p[0] <= p[1] {
+                for y in 0..8 {
+                    let flags = source.read_u8()?;
+                    for x in 0..8 { set(x, y, p[(flags >> x) as usize & 1]); }
+                }
+            } else {
+                let mut flags = source.read_u16::<LittleEndian>()?;
+
+                for y in (0..8).step_by(2) {
+                    for x in (0..8).step_by(2) {
+                        let color = p[flags as usize & 1];
+                        flags >>= 1;
+
+                        for (dx, dy) in [(0, 0), (1, 0), (0, 1), (1, 1)] { set(x + dx, y + dy, color); }
+                    }
+                }
+            }
+        }
+        0x8 => {
+            let mut p = colors::<_, 2>(source)?;
+
+            if p[0] <= p[1] {
+                for index in 0..4 {
+                    if index > 0 { p = colors(source)?; }
+
+                    let mut flags = source.read_u16::<LittleEndian>()?;
+                    let (qx, qy) = quadrant(index);
+
+                    for y in 0..4 {
+                        for x in 0..4 {
+                            set(qx + x, qy + y, p[flags as usize & 1]);
+                            flags >>= 1;
+                        }
+                    }
+                }
+            } else {
+                let mut flags = source.read_u32::<LittleEndian>()?;
+                let q = colors::<_, 2>(source)?;
+
+                if q[0] <= q[1] {
+                    for (half, p) in [p, q].iter().enumerate() {
+                        if half > 0 { flags = source.read_u32::<LittleEndian>()?; }
+
+                        for y in 0..8 {
+                            for x in 0..4 {
+                                set(half * 4 + x, y, p[flags as usize & 1]);
+                                flags >>= 1;
+                            }
+                        }
+                    }
+                } else {
+                    for (half, p) in [p, q].iter().enumerate() {
+                        if half > 0 { flags = source.read_u32::<LittleEndian>()?; }
+
+                        for y in 0..4 {
+                            for x in 0..8 {
+                                set(x, half * 4 + y, p[flags as usize & 1]);
+                                flags >>= 1;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        0x9 => {
+            let p = colors::<_, 4>(source)?;
+
+            if p[0] <= p[1] {
+                if p[2] <= p[3] {
+                    for y in 0..8 {
+                        let mut flags = source.read_u16::<LittleEndian>()?;
+
+                        for x in 0..8 {
+                            set(x, y, p[flags as usize & 3]);
+                            flags >>= 2;
+                        }
+                    }
+                } else {
+                    let mut flags = source.read_u32::<LittleEndian>()?;
+
+                    for y in (0..8).step_by(2) {
+                        for x in (0..8).step_by(2) {
+                            let color = p[flags as usize & 3];
+                            flags >>= 2;
+
+                            for (dx, dy) in [(0, 0), (1, 0), (0, 1), (1, 1)] { set(x + dx, y + dy, color); }
+                        }
+                    }
+                }
+            } else {
+                let mut flags = source.read_u64::<LittleEndian>()?;
+
+                if p[2] <= p[3] {
+                    for y in 0..8 {
+                        for x in (0..8).step_by(2) {
+                            let color = p[flags as usize & 3];
+                            flags >>= 2;
+
+                            set(x, y, color);
+                            set(x + 1, y, color);
+                        }
+                    }
+                } else {
+                    for y in (0..8).step_by(2) {
+                        for x in 0..8 {
+                            let color = p[flags as usize & 3];
+                            flags >>= 2;
+
+                            set(x, y, color);
+                            set(x, y + 1, color);
+                        }
+                    }
+                }
+            }
+        }
+        0xA => {
+            let mut p = colors::<_, 4>(source)?;
+
+            if p[0] <= p[1] {
+                for index in 0..4 {
+                    if index > 0 { p = colors(source)?; }
+
+                    let mut flags = source.read_u32::<LittleEndian>()?;
+                    let (qx, qy) = quadrant(index);
+
+                    for y in 0..4 {
+                        for x in 0..4 {
+                            set(qx + x, qy + y, p[flags as usize & 3]);
+                            flags >>= 2;
+                        }
+                    }
+                }
+            } else {
+                let mut flags = source.read_u64::<LittleEndian>()?;
+                let q = colors::<_, 4>(source)?;
+                let vertical = q[0] <= q[1];
+
+                for (half, p) in [p, q].iter().enumerate() {
+                    if half > 0 { flags = source.read_u64::<LittleEndian>()?; }
+
+                    for index in 0..32 {
+                        let (x, y) = if vertical {
+                            (half * 4 + index % 4, index / 4)
+                        } else {
+                            (index % 8, half * 4 + index / 8)
+                        };
+
+                        set(x, y, p[flags as usize & 3]);
+                        flags >>= 2;
+                    }
+                }
+            }
+        }
+        0xB => source.read_exact(&mut pixels)?,
+        0xC => {
+            for y in (0..8).step_by(2) {
+                for x in (0..8).step_by(2) {
+                    let color = source.read_u8()?;
+                    for (dx, dy) in [(0, 0), (1, 0), (0, 1), (1, 1)] { set(x + dx, y + dy, color); }
+                }
+            }
+        }
+        0xD => {
+            let p = colors::<_, 4>(source)?;
+
+            for y in 0..8 {
+                for x in 0..8 { set(x, y, p[y / 4 * 2 + x / 4]); }
+            }
+        }
+        0xE => pixels = [source.read_u8()?; 64],
+        0xF => {
+            let p = colors::<_, 2>(source)?;
+
+            for y in 0..8 {
+                for x in 0..8 { set(x, y, p[(x ^ y) & 1]); }
+            }
+        }
+        _ => return Err(Error::Format)
+    }
+
+    Ok(pixels)
+}
+
+pub(crate) fn frame<S: Read>(source: &mut S, map: &[u8], buffers: &mut Buffers) -> Result<(), Error> {
+    let columns = buffers.width / 8;
+    let rows = buffers.height / 8;
+
+    if map.len() * 2 < columns * rows { return Err(Error::Format); }
+
+    for index in 0..columns * rows {
+        let opcode = (map[index / 2] >> (index % 2 * 4)) & 0x0F;
+        let mut block = Block { buffers, x: index % columns * 8, y: index / columns * 8 };
+
+        match opcode {
+            0x0 => block.copy(Source::Last, 0, 0)?,
+            0x1 => block.copy(Source::Second, 0, 0)?,
+            0x2 => {
+                let (x, y) = motion(source.read_u8()?);
+                block.copy(Source::Second, x, y)?
+            }
+            0x3 => {
+                let (x, y) = motion(source.read_u8()?);
+                block.copy(Source::Current, -x, -y)?
+            }
+            0x4 => {
+                let byte = source.read_u8()? as i32;
+                block.copy(Source::Last, -8 + (byte & 0x0F), -8 + (byte >> 4))?
+            }
+            0x5 => {
+                let x = source.read_i8()? as i32;
+                let y = source.read_i8()? as i32;
+                block.copy(Source::Last, x, y)?
+            }
+            0x6 => {}
+            opcode => block.fill(&pixels(source, opcode)?),
+        }
+    }
+
+    buffers.rotate();
+
+    Ok(())
+}
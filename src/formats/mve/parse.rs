@@ -0,0 +1,22 @@
+use std::io::{Read, Seek, SeekFrom};
+
+use byteorder::{LittleEndian, ReadBytesExt};
+
+use crate::common::types::errors::Error;
+
+use super::decode::Decoder;
+
+pub fn decoder<S: Read + Seek>(mut source: S) -> Result<Decoder<S>, Error> {
+    source.seek(SeekFrom::Start(0))?;
+
+    let mut signature = [0u8; 20];
+    source.read_exact(&mut signature)?;
+
+    if &signature != b"Interplay MVE File\x1A\x00" { return Err(Error::Format); }
+
+    if source.read_u16::<LittleEndian>()? != 0x001A { return Err(Error::Format); }
+    if source.read_u16::<LittleEndian>()? != 0x0100 { return Err(Error::Format); }
+    if source.read_u16::<LittleEndian>()? != 0x1133 { return Err(Error::Format); }
+
+    Ok(Decoder::new(source))
+}
@@ -2,6 +2,9 @@ use crate::common::graphics::Pixel;
 
 pub mod parse;
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Palette {
+    #[cfg_attr(feature = "serde", serde(with = "crate::common::array"))]
     pub colors: [Option<Pixel<u8>>; 256],
 }
@@ -1,7 +1,10 @@
 pub mod parse;
+pub mod write;
+pub mod database;
 pub(crate) mod traits;
 
-#[derive(Debug, Hash, Eq, PartialEq)]
+#[derive(Debug, Hash, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Type<I, C, S, W, T, M> {
     Item(I),
     Critter(C),
@@ -29,6 +32,8 @@ pub type ObjectPatch = Type<
     object::misc::Patch,
 >;
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Prototype {
     pub index: u16,
     pub meta: meta::Instance,
@@ -41,16 +46,24 @@ pub mod meta {
     use crate::common::types::models;
     use crate::common::types::models::Identifier;
 
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct Instance {
         pub light: info::Light,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::common::sorted::set"))]
         pub flags: HashSet<info::flags::Root>,
         pub sprite: Identifier<models::sprite::Kind>,
         pub connections: info::Connections,
+
+        pub _flags: u32, // bits the flags above don't account for
+        pub _light: [u32; 2], // bits of light's distance and intensity words above their scales
     }
 
-    #[derive(Debug, Eq, PartialEq)]
+    #[derive(Debug, Eq, PartialEq, Clone)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct Patch {
         pub light: info::Light,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::common::sorted::set"))]
         pub flags: HashSet<info::flags::Root>,
     }
 
@@ -59,18 +72,22 @@ pub mod meta {
 
         use crate::common::types::geometry::Scaled;
 
-        #[derive(Debug, Hash, Eq, PartialEq)]
+        #[derive(Debug, Hash, Eq, PartialEq, Clone)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct Light {
             pub distance: Scaled<u8, RangeInclusive<u8>>,
             pub intensity: Scaled<u16, RangeInclusive<u16>>,
         }
 
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct Connections {
             pub description_id: u32,
         }
 
         pub mod flags {
-            #[derive(Debug, PartialEq, Eq, Hash)]
+            #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub enum Transparency {
                 Wall,
                 Glass,
@@ -80,7 +97,8 @@ pub mod meta {
                 End,
             }
 
-            #[derive(Debug, PartialEq, Eq, Hash)]
+            #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub enum Root {
                 Flat,
                 NotBlocking,
@@ -99,7 +117,8 @@ pub mod meta {
 pub mod object {
     pub mod common {
         pub mod world {
-            #[derive(Debug)]
+            #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub enum Material {
                 Glass,
                 Metal,
@@ -111,7 +130,8 @@ pub mod object {
                 Leather,
             }
 
-            #[derive(Debug, PartialEq, Eq, Hash)]
+            #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub enum Light {
                 Vertical,
                 Horizontal,
@@ -126,14 +146,16 @@ pub mod object {
             use crate::common::types::geometry::Coordinate;
             use crate::common::types::space::Elevation;
 
-            #[derive(Debug, Hash, Eq, PartialEq)]
+            #[derive(Debug, Hash, Eq, PartialEq, Clone)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub enum Map {
                 Local(u32),
                 Current,
                 World,
             }
 
-            #[derive(Debug, Hash, Eq, PartialEq)]
+            #[derive(Debug, Hash, Eq, PartialEq, Clone)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct Destination {
                 pub elevation: Elevation,
                 pub position: Coordinate<u8, std::ops::Range<u8>>,
@@ -142,6 +164,7 @@ pub mod object {
 
         pub mod critter {
             #[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub enum Animation {
                 Stand,
                 Walk,
@@ -209,7 +232,8 @@ pub mod object {
                 CalledShotPic,
             }
 
-            #[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+            #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub enum Statistic {
                 Strength,
                 Perception,
@@ -251,7 +275,8 @@ pub mod object {
                 CurrentRadiationLevel,
             }
 
-            #[derive(Debug)]
+            #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub enum Perk {
                 Awareness,
                 BonusHtHAttacks,
@@ -320,7 +345,8 @@ pub mod object {
                 Unknown(u32),
             }
 
-            #[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+            #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub enum Skill {
                 SmallGuns,
                 BigGuns,
@@ -342,14 +368,37 @@ pub mod object {
                 Outdoorsman,
             }
 
-            #[derive(Debug, PartialEq, Eq, Hash)]
+            #[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+            pub enum Trait {
+                FastMetabolism,
+                Bruiser,
+                SmallFrame,
+                OneHander,
+                Finesse,
+                Kamikaze,
+                HeavyHanded,
+                FastShot,
+                BloodyMess,
+                Jinxed,
+                GoodNatured,
+                ChemReliant,
+                ChemResistant,
+                SexAppeal,
+                Skilled,
+                Gifted,
+            }
+
+            #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub enum Gender {
                 Male,
                 Female,
             }
 
             pub mod body {
-                #[derive(Debug)]
+                #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+                #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
                 pub enum Type {
                     Biped,
                     Quadruped,
@@ -360,7 +409,8 @@ pub mod object {
 
         pub mod combat {
             pub mod damage {
-                #[derive(Debug, PartialEq, Eq, Hash)]
+                #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+                #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
                 pub enum Type {
                     Default,
                     Laser,
@@ -376,7 +426,8 @@ pub mod object {
         }
 
         pub mod weapons {
-            #[derive(Debug)]
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub enum Caliber {
                 Rocket,
                 FlamethrowerFuel,
@@ -396,14 +447,16 @@ pub mod object {
         }
 
         pub mod actions {
-            #[derive(Debug, PartialEq, Eq, Hash)]
+            #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct Usage {
                 pub itself: bool,
                 pub something: bool,
                 pub knees_down: bool,
             }
 
-            #[derive(Debug, PartialEq, Eq, Hash)]
+            #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub enum Instance {
                 Look,
                 Talk,
@@ -419,7 +472,8 @@ pub mod object {
         use crate::common::types::models;
         use crate::common::types::models::Identifier;
 
-        #[derive(Debug, PartialEq, Eq, Hash)]
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub enum Flag {
             Hidden
         }
@@ -444,7 +498,10 @@ pub mod object {
             key::Patch,
         >;
 
-        #[derive(Debug, Eq, PartialEq)]
+        pub type ItemType = Type<(), (), (), (), (), (), ()>;
+
+        #[derive(Debug, Hash, Eq, PartialEq, Clone)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub enum Type<Ar, C, D, W, Am, M, K> {
             Armor(Ar),
             Container(C),
@@ -455,17 +512,23 @@ pub mod object {
             Key(K),
         }
 
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct Connections {
             pub _sounds_ids: u8,
         }
 
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct Instance {
             pub r#type: Body,
+            #[cfg_attr(feature = "serde", serde(serialize_with = "crate::common::sorted::set"))]
             pub flags: HashSet<Flag>,
 
             pub sprite: Option<Identifier<models::sprite::Kind>>,
             pub script: Option<Identifier<models::script::Type>>,
 
+            #[cfg_attr(feature = "serde", serde(serialize_with = "crate::common::sorted::set"))]
             pub actions: HashSet<super::common::actions::Instance>,
             pub material: super::common::world::Material,
 
@@ -474,6 +537,7 @@ pub mod object {
             pub weight: u32,
 
             pub connections: Connections,
+            pub _flags: u32, // bits of flags and attack modes the fields above don't account for
         }
 
         pub mod armor {
@@ -484,15 +548,21 @@ pub mod object {
 
             use super::super::common::{combat::damage, critter};
 
-            #[derive(Debug)]
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct Appearance {
+                #[cfg_attr(feature = "serde", serde(serialize_with = "crate::common::sorted::map"))]
                 pub sprites: HashMap<critter::Gender, Identifier<models::sprite::Kind>>,
             }
 
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct Instance {
                 pub class: u32,
 
+                #[cfg_attr(feature = "serde", serde(serialize_with = "crate::common::sorted::map"))]
                 pub threshold: HashMap<damage::Type, u32>,
+                #[cfg_attr(feature = "serde", serde(serialize_with = "crate::common::sorted::map"))]
                 pub resistance: HashMap<damage::Type, u32>,
 
                 pub perk: Option<critter::Perk>,
@@ -503,15 +573,20 @@ pub mod object {
         pub mod container {
             use std::collections::HashSet;
 
-            #[derive(Debug, PartialEq, Eq, Hash)]
+            #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub enum Flag {
                 NoPickUp,
                 MagicHands,
             }
 
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct Instance {
                 pub size: u32,
+                #[cfg_attr(feature = "serde", serde(serialize_with = "crate::common::sorted::set"))]
                 pub flags: HashSet<Flag>,
+                pub _flags: u32,
             }
         }
 
@@ -522,49 +597,69 @@ pub mod object {
             use crate::common::types::geometry::Scaled;
             use crate::formats::pro::object::common::critter::Statistic;
 
-            #[derive(Debug)]
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub enum Amount {
                 Fixed(i32),
                 Random(RangeInclusive<i32>),
             }
 
-            #[derive(Debug)]
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct Effect {
                 pub delay: Option<std::time::Duration>,
                 pub impact: Amount,
             }
 
-            #[derive(Debug)]
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct Addiction {
                 pub perk: super::super::common::critter::Perk,
                 pub delay: std::time::Duration,
                 pub chance: Scaled<u8, RangeInclusive<u8>>,
             }
 
-            #[derive(Debug)]
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct Instance {
+                #[cfg_attr(feature = "serde", serde(serialize_with = "crate::common::sorted::map"))]
                 pub effects: HashMap<Statistic, [Effect; 3]>,
                 pub addiction: Option<Addiction>,
+                pub _unknown: Unknown,
+            }
+
+            // Words as read, written back where effects and addiction don't cover them: statistics also keep
+            // effects' slots, amounts and durations of unused slots, addiction's chance and delay without a perk.
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+            pub struct Unknown {
+                pub statistics: [i32; 3],
+                pub amounts: [[i32; 3]; 3],
+                pub durations: [u32; 2],
+                pub addiction: [u32; 2],
             }
         }
 
         pub mod weapon {
             use std::collections::HashSet;
 
-            #[derive(Debug, PartialEq, Eq, Hash)]
+            #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub enum Flag {
                 BigGun,
                 SecondHand,
             }
 
-            #[derive(Debug)]
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct Damage {
                 pub value: std::ops::RangeInclusive<u32>,
                 pub r#type: super::super::common::combat::damage::Type,
             }
 
             pub mod attack {
-                #[derive(Debug, PartialEq, Eq, Hash)]
+                #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+                #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
                 pub enum Mode {
                     Punch,
                     Kick,
@@ -576,7 +671,8 @@ pub mod object {
                     Flame,
                 }
 
-                #[derive(Debug, PartialEq, Eq, Hash)]
+                #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+                #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
                 pub struct Instance {
                     pub cost: u32,
                     pub mode: Mode,
@@ -584,7 +680,8 @@ pub mod object {
                 }
             }
 
-            #[derive(Debug, Eq, PartialEq)]
+            #[derive(Debug, Eq, PartialEq, Clone, Hash)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub enum Animation {
                 Knife,
                 Club,
@@ -598,23 +695,28 @@ pub mod object {
                 RocketLauncher,
             }
 
-            #[derive(Debug)]
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct Rounds {
                 pub burst: u32,
                 pub magazine: u32,
             }
 
-            #[derive(Debug)]
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct Ammunition {
                 pub rounds: Rounds,
                 pub caliber: super::super::common::weapons::Caliber,
             }
 
-            #[derive(Debug)]
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct Requirements {
                 pub strength: u32,
             }
 
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct Connections {
                 pub ammo_item_id: Option<u16>,
                 pub failure_list_id: Option<u16>,
@@ -623,7 +725,10 @@ pub mod object {
                 pub _sounds_ids: u8,
             }
 
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct Instance {
+                #[cfg_attr(feature = "serde", serde(serialize_with = "crate::common::sorted::set"))]
                 pub flags: HashSet<Flag>,
                 pub damage: Damage,
                 pub attacks: [Option<attack::Instance>; 2],
@@ -634,9 +739,21 @@ pub mod object {
 
                 pub perk: Option<super::super::common::critter::Perk>,
                 pub connections: Connections,
+                pub _unknown: Unknown,
+            }
+
+            // Words as read, written back where the fields above are missing: range and cost of attacks without
+            // a mode, burst and magazine without a caliber, projectile's header without a projectile.
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+            pub struct Unknown {
+                pub attacks: [[u32; 2]; 2],
+                pub rounds: [u32; 2],
+                pub projectile: u16,
             }
 
-            #[derive(Debug, Eq, PartialEq)]
+            #[derive(Debug, Eq, PartialEq, Clone)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct Patch {
                 pub rounds: u32,
                 pub ammo_item_id: Option<u16>,
@@ -645,53 +762,68 @@ pub mod object {
 
         pub mod ammo {
             pub mod adjustments {
+                #[derive(Debug, Clone, PartialEq, Eq)]
+                #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
                 pub struct Armor {
                     pub class: i32,
                     pub resistance: i32,
                 }
 
+                #[derive(Debug, Clone, PartialEq, Eq)]
+                #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
                 pub struct Damage {
                     pub divider: u32,
                     pub multiplier: u32,
                 }
 
+                #[derive(Debug, Clone, PartialEq, Eq)]
+                #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
                 pub struct Instance {
                     pub armor: Armor,
                     pub damage: Damage,
                 }
             }
 
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct Instance {
                 pub count: u32,
                 pub caliber: Option<super::super::common::weapons::Caliber>,
                 pub adjustments: adjustments::Instance,
             }
 
-            #[derive(Debug, Eq, PartialEq)]
+            #[derive(Debug, Eq, PartialEq, Clone)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct Patch {
                 pub count: u32,
             }
         }
 
         pub mod misc {
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct Connections {
                 pub power_item_id: Option<u32>,
             }
 
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct Instance {
                 pub count: u32,
                 pub caliber: Option<super::super::common::weapons::Caliber>,
                 pub connections: Connections,
             }
 
-            #[derive(Debug, Eq, PartialEq)]
+            #[derive(Debug, Eq, PartialEq, Clone)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct Patch {
                 pub count: Option<u32>,
             }
         }
 
         pub mod key {
-            #[derive(Debug, Eq, PartialEq)]
+            #[derive(Debug, Eq, PartialEq, Clone)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct Instance {
                 pub code: Option<u32>,
             }
@@ -706,7 +838,8 @@ pub mod object {
         use crate::common::types::models;
         use crate::common::types::models::Identifier;
 
-        #[derive(Debug, PartialEq, Eq, Hash)]
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub enum Flag {
             BarterAvailable,
             NoSteal,
@@ -722,7 +855,8 @@ pub mod object {
         }
 
         pub mod murder {
-            #[derive(Debug)]
+            #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub enum Type {
                 Men,
                 Women,
@@ -745,24 +879,31 @@ pub mod object {
                 BigBadBoss,
             }
 
-            #[derive(Debug)]
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct Result {
                 pub r#type: Type,
                 pub experience: u32,
             }
         }
 
-        #[derive(Debug, Eq, PartialEq)]
+        #[derive(Debug, Eq, PartialEq, Clone)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct Statistics {
+            #[cfg_attr(feature = "serde", serde(serialize_with = "crate::common::sorted::map"))]
             pub basic: HashMap<super::common::critter::Statistic, i32>,
+            #[cfg_attr(feature = "serde", serde(serialize_with = "crate::common::sorted::map"))]
             pub bonuses: HashMap<super::common::critter::Statistic, i32>,
         }
 
-        #[derive(Debug, Eq, PartialEq)]
+        #[derive(Debug, Eq, PartialEq, Clone)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct Connections {
             pub ai_packet_id: u32,
         }
 
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct Instance {
             pub team: u32,
 
@@ -773,14 +914,34 @@ pub mod object {
             pub head: Option<Identifier<models::sprite::Kind>>,
             pub script: Option<Identifier<models::script::Type>>,
 
+            #[cfg_attr(feature = "serde", serde(serialize_with = "crate::common::sorted::set"))]
             pub flags: HashSet<Flag>,
+            #[cfg_attr(feature = "serde", serde(serialize_with = "crate::common::sorted::map"))]
             pub skills: HashMap<super::common::critter::Skill, u32>,
 
             pub statistics: Statistics,
             pub connections: Connections,
+
+            pub _unknown: u32, // extended flags, none of them are modelled
+            pub _flags: u32, // bits the flags above don't account for
+        }
+
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub struct Data {
+            #[cfg_attr(feature = "serde", serde(serialize_with = "crate::common::sorted::set"))]
+            pub flags: HashSet<Flag>,
+            pub statistics: Statistics,
+            #[cfg_attr(feature = "serde", serde(serialize_with = "crate::common::sorted::map"))]
+            pub skills: HashMap<super::common::critter::Skill, u32>,
+            pub body: super::common::critter::body::Type,
+            pub murder: murder::Result,
+            pub damage: Option<super::common::combat::damage::Type>, // Fallout™ 2 only
+            pub _flags: u32,
         }
 
-        #[derive(Debug, Eq, PartialEq)]
+        #[derive(Debug, Eq, PartialEq, Clone)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct Patch {
             pub team: u32,
 
@@ -795,7 +956,8 @@ pub mod object {
         use crate::common::types::models;
         use crate::common::types::models::Identifier;
 
-        #[derive(Debug, Eq, PartialEq)]
+        #[derive(Debug, Eq, PartialEq, Clone)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub enum SceneryType<D, S, E, L, G> {
             Door(D),
             Stairs(S),
@@ -820,48 +982,64 @@ pub mod object {
             (),
         >;
 
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct Connections {
             pub _sounds_ids: u8,
         }
 
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct Instance {
             pub body: Body,
 
+            #[cfg_attr(feature = "serde", serde(serialize_with = "crate::common::sorted::set"))]
             pub light: HashSet<super::common::world::Light>,
             pub script: Option<Identifier<models::script::Type>>,
             pub material: super::common::world::Material,
 
+            #[cfg_attr(feature = "serde", serde(serialize_with = "crate::common::sorted::set"))]
             pub actions: HashSet<super::common::actions::Instance>,
             pub connections: Connections,
+            pub _flags: u32, // bits of light and actions the fields above don't account for
         }
 
         pub mod door {
             use std::collections::HashSet;
 
-            #[derive(Debug, PartialEq, Eq, Hash)]
+            #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub enum Flag {
                 Passable
             }
 
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct Instance {
+                #[cfg_attr(feature = "serde", serde(serialize_with = "crate::common::sorted::set"))]
                 pub flags: HashSet<Flag>,
+                pub _flags: u32,
                 pub _unknown: u32,
             }
 
-            #[derive(Debug, Eq, PartialEq)]
+            #[derive(Debug, Eq, PartialEq, Clone)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct Patch {
+                #[cfg_attr(feature = "serde", serde(serialize_with = "crate::common::sorted::set"))]
                 pub flags: HashSet<Flag>,
             }
         }
 
         pub mod stairs {
-            #[derive(Debug, Eq, PartialEq)]
+            #[derive(Debug, Eq, PartialEq, Clone)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct Destination {
                 pub map: super::super::common::map::Map,
                 pub target: Option<super::super::common::map::Destination>,
             }
 
-            #[derive(Debug, Eq, PartialEq)]
+            #[derive(Debug, Eq, PartialEq, Clone)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct Instance {
                 pub destination: Destination,
             }
@@ -870,7 +1048,8 @@ pub mod object {
         }
 
         pub mod elevator {
-            #[derive(Debug, Eq, PartialEq)]
+            #[derive(Debug, Eq, PartialEq, Clone)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct Instance {
                 pub floor: i32,
                 pub r#type: Option<u16>,
@@ -880,18 +1059,22 @@ pub mod object {
         }
 
         pub mod ladder {
-            #[derive(Debug)]
+            #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub enum Direction {
                 Top,
                 Bottom,
             }
 
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct Instance {
                 pub direction: Direction,
                 pub destination: Option<super::super::common::map::Destination>,
             }
 
-            #[derive(Debug, Eq, PartialEq)]
+            #[derive(Debug, Eq, PartialEq, Clone)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct Patch {
                 pub map: Option<super::super::common::map::Map>,
                 pub destination: Option<super::super::common::map::Destination>, // Fallout™ 2 only
@@ -899,6 +1082,8 @@ pub mod object {
         }
 
         pub mod generic {
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct Instance {
                 pub _unknown: u32,
             }
@@ -911,16 +1096,23 @@ pub mod object {
         use crate::common::types::models;
         use crate::common::types::models::Identifier;
 
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct Instance {
+            #[cfg_attr(feature = "serde", serde(serialize_with = "crate::common::sorted::set"))]
             pub light: HashSet<super::common::world::Light>,
             pub script: Option<Identifier<models::script::Type>>,
             pub material: super::common::world::Material,
 
+            #[cfg_attr(feature = "serde", serde(serialize_with = "crate::common::sorted::set"))]
             pub actions: HashSet<super::common::actions::Instance>,
+            pub _flags: u32, // bits of light and actions the fields above don't account for
         }
     }
 
     pub mod tile {
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct Instance {
             pub material: super::common::world::Material,
         }
@@ -931,7 +1123,8 @@ pub mod object {
             use crate::common::types::geometry::Orientation;
             use crate::formats::pro::object::common::map::{Destination, Map};
 
-            #[derive(Debug, Hash, Eq, PartialEq)]
+            #[derive(Debug, Hash, Eq, PartialEq, Clone)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct Instance {
                 pub map: Map,
                 pub destination: Destination,
@@ -939,11 +1132,14 @@ pub mod object {
             }
         }
 
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct Instance {
             pub _unknown: u32,
         }
 
-        #[derive(Debug, Hash, Eq, PartialEq)]
+        #[derive(Debug, Hash, Eq, PartialEq, Clone)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub enum Patch {
             None,
             Exit(exit::Instance),
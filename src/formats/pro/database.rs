@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+
+use crate::common::types::models::Identifier;
+
+use super::*;
+use super::object::{critter, item, scenery};
+use super::object::item::{ammo, armor, drug, weapon, ItemType};
+
+// All prototypes of the game, keyed by their identifiers, as maps and scripts refer to them.
+#[derive(Debug)]
+pub struct Database {
+    entries: Vec<(Identifier<ObjectType>, Prototype)>,
+    identifiers: HashMap<Identifier<ObjectType>, usize>,
+    types: HashMap<ObjectType, Vec<usize>>,
+    items: HashMap<ItemType, Vec<usize>>,
+}
+
+impl Database {
+    // A repeated identifier replaces the prototype given before it, keeping its place.
+    pub fn new(entries: Vec<(Identifier<ObjectType>, Prototype)>) -> Self {
+        let mut database = Self {
+            entries: Vec::new(),
+            identifiers: HashMap::new(),
+            types: HashMap::new(),
+            items: HashMap::new(),
+        };
+
+        for (identifier, prototype) in entries {
+            match database.identifiers.get(&identifier) {
+                Some(position) => database.entries[*position].1 = prototype,
+                None => {
+                    database.identifiers.insert(identifier.clone(), database.entries.len());
+                    database.entries.push((identifier, prototype));
+                }
+            }
+        }
+
+        for (position, (identifier, prototype)) in database.entries.iter().enumerate() {
+            database.types.entry(identifier.kind.clone()).or_default().push(position);
+
+            if let Some(item) = prototype.item() {
+                database.items.entry(subtype(&item.r#type)).or_default().push(position);
+            }
+        }
+
+        database
+    }
+
+    fn select<F: Fn(&Prototype) -> bool>(&self, positions: Option<&Vec<usize>>, filter: F) ->
+    Vec<(&Identifier<ObjectType>, &Prototype)> {
+        positions.map_or(Vec::new(), |positions| positions.iter()
+            .map(|position| &self.entries[*position])
+            .filter(|(_, prototype)| filter(prototype))
+            .map(|(identifier, prototype)| (identifier, prototype))
+            .collect())
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn get(&self, identifier: &Identifier<ObjectType>) -> Option<&Prototype> {
+        self.identifiers.get(identifier).map(|position| &self.entries[*position].1)
+    }
+
+    pub fn filter<F: Fn(&Prototype) -> bool>(&self, filter: F) -> Vec<(&Identifier<ObjectType>, &Prototype)> {
+        self.entries.iter()
+            .filter(|(_, prototype)| filter(prototype))
+            .map(|(identifier, prototype)| (identifier, prototype))
+            .collect()
+    }
+
+    pub fn by_type(&self, kind: &ObjectType) -> Vec<(&Identifier<ObjectType>, &Prototype)> {
+        self.select(self.types.get(kind), |_| true)
+    }
+
+    pub fn by_item_type(&self, kind: &ItemType) -> Vec<(&Identifier<ObjectType>, &Prototype)> {
+        self.select(self.items.get(kind), |_| true)
+    }
+
+    pub fn items<F: Fn(&item::Instance) -> bool>(&self, filter: F) -> Vec<(&Identifier<ObjectType>, &Prototype)> {
+        self.select(self.types.get(&ObjectType::Item(())), |p| p.item().is_some_and(&filter))
+    }
+
+    pub fn armors<F: Fn(&armor::Instance) -> bool>(&self, filter: F) -> Vec<(&Identifier<ObjectType>, &Prototype)> {
+        self.select(self.items.get(&ItemType::Armor(())), |p| p.armor().is_some_and(&filter))
+    }
+
+    pub fn drugs<F: Fn(&drug::Instance) -> bool>(&self, filter: F) -> Vec<(&Identifier<ObjectType>, &Prototype)> {
+        self.select(self.items.get(&ItemType::Drug(())), |p| p.drug().is_some_and(&filter))
+    }
+
+    pub fn weapons<F: Fn(&weapon::Instance) -> bool>(&self, filter: F) -> Vec<(&Identifier<ObjectType>, &Prototype)> {
+        self.select(self.items.get(&ItemType::Weapon(())), |p| p.weapon().is_some_and(&filter))
+    }
+
+    pub fn ammo<F: Fn(&ammo::Instance) -> bool>(&self, filter: F) -> Vec<(&Identifier<ObjectType>, &Prototype)> {
+        self.select(self.items.get(&ItemType::Ammo(())), |p| p.ammo().is_some_and(&filter))
+    }
+
+    pub fn critters<F: Fn(&critter::Instance) -> bool>(&self, filter: F) -> Vec<(&Identifier<ObjectType>, &Prototype)> {
+        self.select(self.types.get(&ObjectType::Critter(())), |p| p.critter().is_some_and(&filter))
+    }
+
+    pub fn scenery<F: Fn(&scenery::Instance) -> bool>(&self, filter: F) -> Vec<(&Identifier<ObjectType>, &Prototype)> {
+        self.select(self.types.get(&ObjectType::Scenery(())), |p| p.scenery().is_some_and(&filter))
+    }
+}
+
+fn subtype(body: &item::Body) -> ItemType {
+    match body {
+        item::Type::Armor(_) => ItemType::Armor(()),
+        item::Type::Container(_) => ItemType::Container(()),
+        item::Type::Drug(_) => ItemType::Drug(()),
+        item::Type::Weapon(_) => ItemType::Weapon(()),
+        item::Type::Ammo(_) => ItemType::Ammo(()),
+        item::Type::Misc(_) => ItemType::Misc(()),
+        item::Type::Key(_) => ItemType::Key(()),
+    }
+}
+
+// Shortcuts to typed parts of prototypes, for sorting and inspecting query results.
+impl Prototype {
+    pub fn item(&self) -> Option<&item::Instance> {
+        if let Type::Item(item) = &self.object { Some(item) } else { None }
+    }
+
+    pub fn critter(&self) -> Option<&critter::Instance> {
+        if let Type::Critter(critter) = &self.object { Some(critter) } else { None }
+    }
+
+    pub fn scenery(&self) -> Option<&scenery::Instance> {
+        if let Type::Scenery(scenery) = &self.object { Some(scenery) } else { None }
+    }
+
+    pub fn armor(&self) -> Option<&armor::Instance> {
+        if let item::Type::Armor(armor) = &self.item()?.r#type { Some(armor) } else { None }
+    }
+
+    pub fn drug(&self) -> Option<&drug::Instance> {
+        if let item::Type::Drug(drug) = &self.item()?.r#type { Some(drug) } else { None }
+    }
+
+    pub fn weapon(&self) -> Option<&weapon::Instance> {
+        if let item::Type::Weapon(weapon) = &self.item()?.r#type { Some(weapon) } else { None }
+    }
+
+    pub fn ammo(&self) -> Option<&ammo::Instance> {
+        if let item::Type::Ammo(ammo) = &self.item()?.r#type { Some(ammo) } else { None }
+    }
+}
@@ -4,30 +4,44 @@ use byteorder::{BigEndian, ReadBytesExt};
 
 use crate::common::types::errors;
 use crate::common::types::models::Identifier;
+use crate::common::types::version::Version;
 use crate::formats::pro::meta::info::Light;
 
 use super::*;
 
-mod instance;
+pub(crate) mod instance;
 pub(crate) mod flags;
 
-pub fn prototype<S: Read + Seek>(source: &mut S) -> Result<Prototype, errors::Error> {
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Mode {
+    Lenient,
+    Strict, // fails on trailing bytes only, reserved and unmodelled fields are not validated
+}
+
+pub fn prototype<S: Read + Seek>(source: &mut S, version: &Version, mode: &Mode) -> Result<Prototype, errors::Error> {
     source.seek(SeekFrom::Start(0))?;
 
     let identifier = Identifier::try_from(source.read_u32::<BigEndian>()?)?;
     let description_id = source.read_u32::<BigEndian>()?;
     let sprite = Identifier::try_from(source.read_u32::<BigEndian>()?)?;
 
-    let light_radius = source.read_u32::<BigEndian>()? as u8;
-    let light_intensity = source.read_u32::<BigEndian>()? as u16;
+    let light_radius = source.read_u32::<BigEndian>()?;
+    let light_intensity = source.read_u32::<BigEndian>()?;
+
+    let flags_raw = source.read_u32::<BigEndian>()?;
+    let flags = flags::common(&mut &flags_raw.to_be_bytes()[..])?;
+    let object = instance::instance(source, &identifier.kind, version)?;
 
-    let flags = flags::common(source)?;
-    let object = instance::instance(source, &identifier.kind)?;
+    if let Mode::Strict = mode {
+        if source.read(&mut [0u8; 1])? != 0 { return Err(errors::Error::Format); }
+    }
 
     Ok(Prototype {
         index: identifier.index,
         meta: meta::Instance {
-            light: Light::try_from((light_radius, light_intensity))?,
+            light: Light::try_from((light_radius as u8, light_intensity as u16))?,
+            _flags: flags_raw & !super::write::flags::bits(&flags),
+            _light: [light_radius & !(u8::MAX as u32), light_intensity & !(u16::MAX as u32)],
             flags,
             sprite,
             connections: meta::info::Connections { description_id },
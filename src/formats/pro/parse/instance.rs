@@ -3,17 +3,17 @@ use super::*;
 mod common;
 
 mod item;
-mod critter;
+pub(crate) mod critter;
 mod scenery;
 mod wall;
 mod tile;
 mod misc;
 
-pub(crate) fn instance<S: Read>(source: &mut S, r#type: &Type<(), (), (), (), (), ()>) ->
+pub(crate) fn instance<S: Read>(source: &mut S, r#type: &Type<(), (), (), (), (), ()>, version: &Version) ->
 Result<ObjectInstance, errors::Error> {
     Ok(match r#type {
         Type::Item(_) => Type::Item(item::instance(source)?),
-        Type::Critter(_) => Type::Critter(critter::instance(source)?),
+        Type::Critter(_) => Type::Critter(critter::instance(source, version)?),
         Type::Scenery(_) => Type::Scenery(scenery::instance(source)?),
         Type::Wall(_) => Type::Wall(wall::instance(source)?),
         Type::Tile(_) => Type::Tile(tile::instance(source)?),
@@ -1,5 +1,3 @@
-use std::io::ErrorKind;
-
 use object::critter::Instance;
 
 use crate::common::traits::TryFromOptional;
@@ -10,9 +8,8 @@ mod flags;
 mod skills;
 mod statistics;
 
-pub(crate) fn instance<S: Read>(source: &mut S) -> Result<Instance, errors::Error> {
-    let mut flags_bytes = [0u8; 4];
-    source.read_exact(&mut flags_bytes)?;
+pub(crate) fn instance<S: Read>(source: &mut S, version: &Version) -> Result<Instance, errors::Error> {
+    let unknown = source.read_u32::<BigEndian>()?;
 
     let script =
         Identifier::try_from_optional(source.read_u32::<BigEndian>()?, 0xFF_FF_FF_FF)?;
@@ -23,7 +20,27 @@ pub(crate) fn instance<S: Read>(source: &mut S) -> Result<Instance, errors::Erro
     let ai_packet_id = source.read_u32::<BigEndian>()?;
     let team = source.read_u32::<BigEndian>()?;
 
-    let flags = flags::instance(source)?;
+    let data = data(source, version)?;
+
+    Ok(Instance {
+        team,
+        murder: data.murder,
+        damage: data.damage,
+        body: data.body,
+        head,
+        script,
+        flags: data.flags,
+        skills: data.skills,
+        statistics: data.statistics,
+        connections: object::critter::Connections { ai_packet_id },
+        _unknown: unknown,
+        _flags: data._flags,
+    })
+}
+
+// Part shared by prototypes and characters' records, e.g. `.gcd` files and saves.
+pub(crate) fn data<S: Read>(source: &mut S, version: &Version) -> Result<object::critter::Data, errors::Error> {
+    let (flags, flags_rest) = flags::instance(source)?;
 
     let basic = statistics::map(source)?;
     let bonuses = statistics::map(source)?;
@@ -31,34 +48,24 @@ pub(crate) fn instance<S: Read>(source: &mut S) -> Result<Instance, errors::Erro
     let skills = skills::map(source)?;
     let body = object::common::critter::body::Type::try_from(source.read_u32::<BigEndian>()?)?;
 
-    let kill_reward = source.read_u32::<BigEndian>()?;
+    let experience = source.read_u32::<BigEndian>()?;
     let kill_type = object::critter::murder::Type::try_from(source.read_u32::<BigEndian>()?)?;
-
-    let damage_type = {
-        match source.read_u32::<BigEndian>() {
-            Ok(v) => { Some(object::common::combat::damage::Type::try_from(v as u8)?) }
-            Err(error) => {
-                match error.kind() {
-                    ErrorKind::UnexpectedEof => None, // file is from Fallout™ 1
-                    _ => return Err(errors::Error::IO(error))
-                }
-            }
+    let damage = match version {
+        Version::First => None,
+        Version::Second => {
+            Some(object::common::combat::damage::Type::try_from(
+                u8::try_from(source.read_u32::<BigEndian>()?).map_err(|_| errors::Error::Format)?
+            )?)
         }
     };
 
-    Ok(Instance {
-        team,
-        murder: object::critter::murder::Result {
-            r#type: kill_type,
-            experience: kill_reward,
-        },
-        damage: damage_type,
-        body,
-        head,
-        script,
+    Ok(object::critter::Data {
         flags,
-        skills,
         statistics: object::critter::Statistics { basic, bonuses },
-        connections: object::critter::Connections { ai_packet_id },
+        skills,
+        body,
+        murder: object::critter::murder::Result { r#type: kill_type, experience },
+        damage,
+        _flags: flags_rest,
     })
 }
@@ -2,7 +2,8 @@ use std::collections::HashSet;
 
 use super::super::super::*;
 
-pub(crate) fn instance<S: Read>(source: &mut S) -> Result<HashSet<object::critter::Flag>, errors::Error> {
+// Flags with the bits they don't account for.
+pub(crate) fn instance<S: Read>(source: &mut S) -> Result<(HashSet<object::critter::Flag>, u32), errors::Error> {
     let mut flags_bytes = [0u8; 4];
     source.read_exact(&mut flags_bytes)?;
 
@@ -53,5 +54,5 @@ pub(crate) fn instance<S: Read>(source: &mut S) -> Result<HashSet<object::critte
         return Err(errors::Error::Format);
     }
 
-    Ok(flags)
+    Ok((flags, u32::from_be_bytes(flags_bytes) & !0x00_00_7F_E2))
 }
\ No newline at end of file
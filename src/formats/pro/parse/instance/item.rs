@@ -50,6 +50,10 @@ pub(crate) fn instance<S: Read>(source: &mut S) -> Result<Instance, errors::Erro
     let sound_ids = source.read_u8()?;
     let r#type = r#type::instance(source, type_id, weapon_flags, attack_modes)?;
 
+    // weapon flags and attack modes are modelled for weapons only
+    let known = if let object::item::Type::Weapon(_) = r#type { 0x08_00_9B_FF } else { 0x08_00_98_00 };
+    let flags_raw = u32::from_be_bytes([flags_bytes[0], flags_bytes[1], flags_bytes[2], attack_modes]);
+
     Ok(Instance {
         r#type,
         flags,
@@ -63,5 +67,6 @@ pub(crate) fn instance<S: Read>(source: &mut S) -> Result<Instance, errors::Erro
         connections: object::item::Connections {
             _sounds_ids: sound_ids,
         },
+        _flags: flags_raw & !known,
     })
 }
\ No newline at end of file
@@ -24,5 +24,5 @@ pub(crate) fn instance<S: Read>(source: &mut S) -> Result<Instance, errors::Erro
         }
     }
 
-    Ok(Instance { size, flags })
+    Ok(Instance { size, flags, _flags: u32::from_be_bytes(flags_bytes) & !0x00_00_00_09 })
 }
\ No newline at end of file
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 use std::time::Duration;
 
 use object::common::critter::{Perk, Statistic};
-use object::item::drug::{Effect, Instance};
+use object::item::drug::{Effect, Instance, Unknown};
 
 use crate::common::traits::TryFromOptional;
 use crate::common::types::geometry::Scaled;
@@ -120,6 +120,27 @@ pub(crate) fn instance<S: Read>(source: &mut S) -> Result<Instance, errors::Erro
     let addiction_perk = Perk::try_from_optional(source.read_i32::<BigEndian>()?, -1)?;
     let addiction_delay_raw = source.read_u32::<BigEndian>()?;
 
+    // amounts of slots holding a statistic, or the minimums of the following one, are kept in effects
+    let statistics = [stat0_raw, stat1_raw, stat2_raw];
+    let mut amounts = [
+        [effect0_amount0_raw, effect0_amount1_raw, effect0_amount2_raw],
+        [effect1_amount0_raw, effect1_amount1_raw, effect1_amount2_raw],
+        [effect2_amount0_raw, effect2_amount1_raw, effect2_amount2_raw],
+    ];
+
+    for slot in 0..statistics.len() {
+        if statistics[slot] >= 0 || (statistics[slot] == -2 && statistics.get(slot + 1).is_some_and(|next| *next >= 0)) {
+            for amounts in &mut amounts { amounts[slot] = 0; }
+        }
+    }
+
+    let unknown = Unknown {
+        statistics,
+        amounts,
+        durations: if effects.is_empty() { [effect1_duration_raw, effect2_duration_raw] } else { [0, 0] },
+        addiction: if addiction_perk.is_none() { [addiction_rate_raw, addiction_delay_raw] } else { [0, 0] },
+    };
+
     Ok(Instance {
         effects,
         addiction: match addiction_perk {
@@ -128,10 +149,11 @@ pub(crate) fn instance<S: Read>(source: &mut S) -> Result<Instance, errors::Erro
                 perk,
                 delay: Duration::new(addiction_delay_raw as u64 * 60, 0),
                 chance: Scaled {
-                    value: addiction_rate_raw as u8,
+                    value: u8::try_from(addiction_rate_raw).map_err(|_| errors::Error::Format)?,
                     scale: 0u8..=100u8,
                 },
             })
         },
+        _unknown: unknown,
     })
 }
\ No newline at end of file
@@ -3,7 +3,7 @@ use std::collections::HashSet;
 use object::common::combat::damage;
 use object::common::critter::Perk;
 use object::common::weapons::Caliber;
-use object::item::weapon::{Animation, attack, Damage, Instance};
+use object::item::weapon::{Animation, attack, Damage, Instance, Unknown};
 
 use crate::common::traits::TryFromOptional;
 
@@ -19,7 +19,9 @@ pub(crate) fn instance<S: Read>(source: &mut S,
 
     let damage = Damage {
         value: source.read_u32::<BigEndian>()?..=source.read_u32::<BigEndian>()?,
-        r#type: damage::Type::try_from(source.read_u32::<BigEndian>()? as u8)?,
+        r#type: damage::Type::try_from(
+            u8::try_from(source.read_u32::<BigEndian>()?).map_err(|_| errors::Error::Format)?
+        )?,
     };
 
 
@@ -65,6 +67,15 @@ pub(crate) fn instance<S: Read>(source: &mut S,
     let capacity = source.read_u32::<BigEndian>()?;
     let sound_ids = source.read_u8()?;
 
+    let unknown = Unknown {
+        attacks: [
+            if attack1.is_none() { [dmg_range_max1, cost1] } else { [0, 0] },
+            if attack2.is_none() { [dmg_range_max2, cost2] } else { [0, 0] },
+        ],
+        rounds: if caliber.is_none() { [burst_count, capacity] } else { [0, 0] },
+        projectile: if projectile_idx == 0xFFFF { projectile_header } else { 0xFFFF },
+    };
+
     Ok(Instance {
         flags,
         damage,
@@ -89,5 +100,6 @@ pub(crate) fn instance<S: Read>(source: &mut S,
             projectile_misc_id: if projectile_idx != 0xFFFF { Some(projectile_idx) } else { None },
             _sounds_ids: sound_ids,
         },
+        _unknown: unknown,
     })
 }
\ No newline at end of file
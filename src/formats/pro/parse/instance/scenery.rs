@@ -19,6 +19,7 @@ pub(crate) fn instance<S: Read>(source: &mut S) -> Result<Instance, errors::Erro
     source.read_exact(&mut actions_bytes)?;
 
     let actions: HashSet<object::common::actions::Instance> = actions::extract(actions_bytes[1])?;
+    let flags_raw = u32::from_be_bytes([light_bytes[0], light_bytes[1], actions_bytes[0], actions_bytes[1]]);
 
     let script =
         Identifier::try_from_optional(source.read_u32::<BigEndian>()?, 0xFF_FF_FF_FF)?;
@@ -37,5 +38,6 @@ pub(crate) fn instance<S: Read>(source: &mut S) -> Result<Instance, errors::Erro
         connections: object::scenery::Connections {
             _sounds_ids: sound_ids
         },
+        _flags: flags_raw & !0xF8_00_00_98,
     })
 }
\ No newline at end of file
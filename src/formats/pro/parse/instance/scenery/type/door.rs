@@ -21,5 +21,11 @@ pub(crate) fn instance<S: Read>(source: &mut S) -> Result<object::scenery::door:
         Ok(value) => value,
     };
 
-    Ok(object::scenery::door::Instance { flags, _unknown: u32::from_be_bytes(unknown_bytes) })
+    let known = if flags.is_empty() { 0x00 } else { 0x0F };
+
+    Ok(object::scenery::door::Instance {
+        flags,
+        _flags: u32::from_be_bytes(flags_bytes) & !known,
+        _unknown: u32::from_be_bytes(unknown_bytes),
+    })
 }
\ No newline at end of file
@@ -280,6 +280,205 @@ impl TryFrom<i32> for object::common::critter::Statistic {
     }
 }
 
+impl TryFrom<i32> for object::common::critter::Skill {
+    type Error = errors::Error;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::SmallGuns),
+            1 => Ok(Self::BigGuns),
+            2 => Ok(Self::EnergyWeapons),
+            3 => Ok(Self::Unarmed),
+            4 => Ok(Self::MeleeWeapons),
+            5 => Ok(Self::Throwing),
+            6 => Ok(Self::FirstAid),
+            7 => Ok(Self::Doctor),
+            8 => Ok(Self::Sneak),
+            9 => Ok(Self::Lockpick),
+            10 => Ok(Self::Steal),
+            11 => Ok(Self::Traps),
+            12 => Ok(Self::Science),
+            13 => Ok(Self::Repair),
+            14 => Ok(Self::Speech),
+            15 => Ok(Self::Barter),
+            16 => Ok(Self::Gambling),
+            17 => Ok(Self::Outdoorsman),
+            _ => Err(errors::Error::Format)
+        }
+    }
+}
+
+impl TryFrom<i32> for object::common::critter::Trait {
+    type Error = errors::Error;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::FastMetabolism),
+            1 => Ok(Self::Bruiser),
+            2 => Ok(Self::SmallFrame),
+            3 => Ok(Self::OneHander),
+            4 => Ok(Self::Finesse),
+            5 => Ok(Self::Kamikaze),
+            6 => Ok(Self::HeavyHanded),
+            7 => Ok(Self::FastShot),
+            8 => Ok(Self::BloodyMess),
+            9 => Ok(Self::Jinxed),
+            10 => Ok(Self::GoodNatured),
+            11 => Ok(Self::ChemReliant),
+            12 => Ok(Self::ChemResistant),
+            13 => Ok(Self::SexAppeal),
+            14 => Ok(Self::Skilled),
+            15 => Ok(Self::Gifted),
+            _ => Err(errors::Error::Format)
+        }
+    }
+}
+
+impl From<object::common::critter::Trait> for i32 {
+    fn from(value: object::common::critter::Trait) -> Self {
+        match value {
+            object::common::critter::Trait::FastMetabolism => 0,
+            object::common::critter::Trait::Bruiser => 1,
+            object::common::critter::Trait::SmallFrame => 2,
+            object::common::critter::Trait::OneHander => 3,
+            object::common::critter::Trait::Finesse => 4,
+            object::common::critter::Trait::Kamikaze => 5,
+            object::common::critter::Trait::HeavyHanded => 6,
+            object::common::critter::Trait::FastShot => 7,
+            object::common::critter::Trait::BloodyMess => 8,
+            object::common::critter::Trait::Jinxed => 9,
+            object::common::critter::Trait::GoodNatured => 10,
+            object::common::critter::Trait::ChemReliant => 11,
+            object::common::critter::Trait::ChemResistant => 12,
+            object::common::critter::Trait::SexAppeal => 13,
+            object::common::critter::Trait::Skilled => 14,
+            object::common::critter::Trait::Gifted => 15,
+        }
+    }
+}
+
+impl From<object::common::critter::Skill> for i32 {
+    fn from(value: object::common::critter::Skill) -> Self {
+        match value {
+            object::common::critter::Skill::SmallGuns => 0,
+            object::common::critter::Skill::BigGuns => 1,
+            object::common::critter::Skill::EnergyWeapons => 2,
+            object::common::critter::Skill::Unarmed => 3,
+            object::common::critter::Skill::MeleeWeapons => 4,
+            object::common::critter::Skill::Throwing => 5,
+            object::common::critter::Skill::FirstAid => 6,
+            object::common::critter::Skill::Doctor => 7,
+            object::common::critter::Skill::Sneak => 8,
+            object::common::critter::Skill::Lockpick => 9,
+            object::common::critter::Skill::Steal => 10,
+            object::common::critter::Skill::Traps => 11,
+            object::common::critter::Skill::Science => 12,
+            object::common::critter::Skill::Repair => 13,
+            object::common::critter::Skill::Speech => 14,
+            object::common::critter::Skill::Barter => 15,
+            object::common::critter::Skill::Gambling => 16,
+            object::common::critter::Skill::Outdoorsman => 17,
+        }
+    }
+}
+
+impl From<object::common::critter::Statistic> for i32 {
+    fn from(value: object::common::critter::Statistic) -> Self {
+        match value {
+            object::common::critter::Statistic::Strength => 0,
+            object::common::critter::Statistic::Perception => 1,
+            object::common::critter::Statistic::Endurance => 2,
+            object::common::critter::Statistic::Charisma => 3,
+            object::common::critter::Statistic::Intelligence => 4,
+            object::common::critter::Statistic::Agility => 5,
+            object::common::critter::Statistic::Luck => 6,
+            object::common::critter::Statistic::MaximumHitPoints => 7,
+            object::common::critter::Statistic::MaximumActionPoints => 8,
+            object::common::critter::Statistic::ArmorClass => 9,
+            object::common::critter::Statistic::UnarmedDamage => 10,
+            object::common::critter::Statistic::MeleeDamage => 11,
+            object::common::critter::Statistic::CarryWeight => 12,
+            object::common::critter::Statistic::Sequence => 13,
+            object::common::critter::Statistic::HealingRate => 14,
+            object::common::critter::Statistic::CriticalChance => 15,
+            object::common::critter::Statistic::BetterCriticals => 16,
+            object::common::critter::Statistic::DamageThreshold => 17,
+            object::common::critter::Statistic::DamageThresholdLaser => 18,
+            object::common::critter::Statistic::DamageThresholdFire => 19,
+            object::common::critter::Statistic::DamageThresholdPlasma => 20,
+            object::common::critter::Statistic::DamageThresholdElectrical => 21,
+            object::common::critter::Statistic::DamageThresholdEMP => 22,
+            object::common::critter::Statistic::DamageThresholdExplosive => 23,
+            object::common::critter::Statistic::DamageResistance => 24,
+            object::common::critter::Statistic::DamageResistanceLaser => 25,
+            object::common::critter::Statistic::DamageResistanceFire => 26,
+            object::common::critter::Statistic::DamageResistancePlasma => 27,
+            object::common::critter::Statistic::DamageResistanceElectrical => 28,
+            object::common::critter::Statistic::DamageResistanceEMP => 29,
+            object::common::critter::Statistic::DamageResistanceExplosive => 30,
+            object::common::critter::Statistic::RadiationResistance => 31,
+            object::common::critter::Statistic::PoisonResistance => 32,
+            object::common::critter::Statistic::Age => 33,
+            object::common::critter::Statistic::Gender => 34,
+            object::common::critter::Statistic::CurrentHitPoints => 35,
+            object::common::critter::Statistic::CurrentPoisonLevel => 36,
+            object::common::critter::Statistic::CurrentRadiationLevel => 37,
+        }
+    }
+}
+
+impl From<&object::common::critter::body::Type> for u32 {
+    fn from(value: &object::common::critter::body::Type) -> Self {
+        match value {
+            object::common::critter::body::Type::Biped => 0,
+            object::common::critter::body::Type::Quadruped => 1,
+            object::common::critter::body::Type::Robotic => 2,
+        }
+    }
+}
+
+impl From<&object::critter::murder::Type> for u32 {
+    fn from(value: &object::critter::murder::Type) -> Self {
+        match value {
+            object::critter::murder::Type::Men => 0,
+            object::critter::murder::Type::Women => 1,
+            object::critter::murder::Type::Children => 2,
+            object::critter::murder::Type::SuperMutants => 3,
+            object::critter::murder::Type::Ghouls => 4,
+            object::critter::murder::Type::Brahmin => 5,
+            object::critter::murder::Type::Radscorpions => 6,
+            object::critter::murder::Type::Rats => 7,
+            object::critter::murder::Type::Floaters => 8,
+            object::critter::murder::Type::Centaurs => 9,
+            object::critter::murder::Type::Robots => 10,
+            object::critter::murder::Type::Dogs => 11,
+            object::critter::murder::Type::Manti => 12,
+            object::critter::murder::Type::DeathClaws => 13,
+            object::critter::murder::Type::Plants => 14,
+            object::critter::murder::Type::Geckos => 15,
+            object::critter::murder::Type::Aliens => 16,
+            object::critter::murder::Type::GiantAnts => 17,
+            object::critter::murder::Type::BigBadBoss => 18,
+        }
+    }
+}
+
+impl From<&object::common::combat::damage::Type> for u8 {
+    fn from(value: &object::common::combat::damage::Type) -> Self {
+        match value {
+            object::common::combat::damage::Type::Default => 0,
+            object::common::combat::damage::Type::Laser => 1,
+            object::common::combat::damage::Type::Fire => 2,
+            object::common::combat::damage::Type::Plasma => 3,
+            object::common::combat::damage::Type::Electrical => 4,
+            object::common::combat::damage::Type::Emp => 5,
+            object::common::combat::damage::Type::Explosive => 6,
+            object::common::combat::damage::Type::Radiation => 7,
+            object::common::combat::damage::Type::Poison => 8,
+        }
+    }
+}
+
 impl TryFrom<u8> for object::common::critter::Animation {
     type Error = errors::Error;
 
@@ -354,6 +553,217 @@ impl TryFrom<u8> for object::common::critter::Animation {
     }
 }
 
+impl From<&object::common::critter::Animation> for u8 {
+    fn from(value: &object::common::critter::Animation) -> Self {
+        match value {
+            object::common::critter::Animation::Stand => 0,
+            object::common::critter::Animation::Walk => 1,
+            object::common::critter::Animation::JumpBegin => 2,
+            object::common::critter::Animation::JumpEnd => 3,
+            object::common::critter::Animation::ClimbLadder => 4,
+            object::common::critter::Animation::Falling => 5,
+            object::common::critter::Animation::UpStairsRight => 6,
+            object::common::critter::Animation::UpStairsLeft => 7,
+            object::common::critter::Animation::DownStairsRight => 8,
+            object::common::critter::Animation::DownStairsLeft => 9,
+            object::common::critter::Animation::MagicHandsGround => 10,
+            object::common::critter::Animation::MagicHandsMiddle => 11,
+            object::common::critter::Animation::MagicHandsUp => 12,
+            object::common::critter::Animation::Dodge => 13,
+            object::common::critter::Animation::HitFromFront => 14,
+            object::common::critter::Animation::HitFromBack => 15,
+            object::common::critter::Animation::ThrowPunch => 16,
+            object::common::critter::Animation::KickLeg => 17,
+            object::common::critter::Animation::Throw => 18,
+            object::common::critter::Animation::Running => 19,
+            object::common::critter::Animation::FallBack => 20,
+            object::common::critter::Animation::FallFront => 21,
+            object::common::critter::Animation::BadLanding => 22,
+            object::common::critter::Animation::BigHole => 23,
+            object::common::critter::Animation::CharredBody => 24,
+            object::common::critter::Animation::ChunksOfFlesh => 25,
+            object::common::critter::Animation::DancingAutofire => 26,
+            object::common::critter::Animation::Electrify => 27,
+            object::common::critter::Animation::SlicedInHalf => 28,
+            object::common::critter::Animation::BurnedToNothing => 29,
+            object::common::critter::Animation::ElectrifiedToNothing => 30,
+            object::common::critter::Animation::ExplodedToNothing => 31,
+            object::common::critter::Animation::MeltedToNothing => 32,
+            object::common::critter::Animation::FireDance => 33,
+            object::common::critter::Animation::FallBackBlood => 34,
+            object::common::critter::Animation::FallFrontBlood => 35,
+            object::common::critter::Animation::ProneToStanding => 36,
+            object::common::critter::Animation::BackToStanding => 37,
+            object::common::critter::Animation::TakeOut => 38,
+            object::common::critter::Animation::PutAway => 39,
+            object::common::critter::Animation::Parry => 40,
+            object::common::critter::Animation::Thrust => 41,
+            object::common::critter::Animation::Swing => 42,
+            object::common::critter::Animation::Point => 43,
+            object::common::critter::Animation::Unpoint => 44,
+            object::common::critter::Animation::FireSingle => 45,
+            object::common::critter::Animation::FireBurst => 46,
+            object::common::critter::Animation::FireContinuous => 47,
+            object::common::critter::Animation::FallBackSf => 48,
+            object::common::critter::Animation::FallFrontSf => 49,
+            object::common::critter::Animation::BadLandingSf => 50,
+            object::common::critter::Animation::BigHoleSf => 51,
+            object::common::critter::Animation::CharredBodySf => 52,
+            object::common::critter::Animation::ChunksOfFleshSf => 53,
+            object::common::critter::Animation::DancingAutofireSf => 54,
+            object::common::critter::Animation::ElectrifySf => 55,
+            object::common::critter::Animation::SlicedInHalfSf => 56,
+            object::common::critter::Animation::BurnedToNothingSf => 57,
+            object::common::critter::Animation::ElectrifiedToNothingSf => 58,
+            object::common::critter::Animation::ExplodedToNothingSf => 59,
+            object::common::critter::Animation::MeltedToNothingSf => 60,
+            object::common::critter::Animation::FallBackBloodSf => 61,
+            object::common::critter::Animation::FallFrontBloodSf => 62,
+            object::common::critter::Animation::CalledShotPic => 64,
+        }
+    }
+}
+
+impl From<&object::item::weapon::Animation> for u32 {
+    fn from(value: &object::item::weapon::Animation) -> Self {
+        match value {
+            object::item::weapon::Animation::Knife => 0x01,
+            object::item::weapon::Animation::Club => 0x02,
+            object::item::weapon::Animation::Sledgehammer => 0x03,
+            object::item::weapon::Animation::Spear => 0x04,
+            object::item::weapon::Animation::Pistol => 0x05,
+            object::item::weapon::Animation::SubmachineGun => 0x06,
+            object::item::weapon::Animation::Rifle => 0x07,
+            object::item::weapon::Animation::BigGun => 0x08,
+            object::item::weapon::Animation::Minigun => 0x09,
+            object::item::weapon::Animation::RocketLauncher => 0x0A,
+        }
+    }
+}
+
+impl From<&object::item::weapon::attack::Mode> for u8 {
+    fn from(value: &object::item::weapon::attack::Mode) -> Self {
+        match value {
+            object::item::weapon::attack::Mode::Punch => 1,
+            object::item::weapon::attack::Mode::Kick => 2,
+            object::item::weapon::attack::Mode::Swing => 3,
+            object::item::weapon::attack::Mode::Thrust => 4,
+            object::item::weapon::attack::Mode::Throw => 5,
+            object::item::weapon::attack::Mode::FireSingle => 6,
+            object::item::weapon::attack::Mode::FireBurst => 7,
+            object::item::weapon::attack::Mode::Flame => 8,
+        }
+    }
+}
+
+impl From<&object::common::world::Material> for u32 {
+    fn from(value: &object::common::world::Material) -> Self {
+        match value {
+            object::common::world::Material::Glass => 0,
+            object::common::world::Material::Metal => 1,
+            object::common::world::Material::Plastic => 2,
+            object::common::world::Material::Wood => 3,
+            object::common::world::Material::Dirt => 4,
+            object::common::world::Material::Stone => 5,
+            object::common::world::Material::Cement => 6,
+            object::common::world::Material::Leather => 7,
+        }
+    }
+}
+
+impl From<&object::common::weapons::Caliber> for u32 {
+    fn from(value: &object::common::weapons::Caliber) -> Self {
+        match value {
+            object::common::weapons::Caliber::Rocket => 1,
+            object::common::weapons::Caliber::FlamethrowerFuel => 2,
+            object::common::weapons::Caliber::CEnergyCell => 3,
+            object::common::weapons::Caliber::DEnergyCell => 4,
+            object::common::weapons::Caliber::Remington223 => 5,
+            object::common::weapons::Caliber::FiveMillimeter => 6,
+            object::common::weapons::Caliber::SnW40 => 7,
+            object::common::weapons::Caliber::TenMillimeter => 8,
+            object::common::weapons::Caliber::Magnum44 => 9,
+            object::common::weapons::Caliber::FourteenMillimeter => 10,
+            object::common::weapons::Caliber::TwelveGauge => 11,
+            object::common::weapons::Caliber::NineMillimeter => 12,
+            object::common::weapons::Caliber::Bb => 13,
+            object::common::weapons::Caliber::Unknown(value) => *value,
+        }
+    }
+}
+
+impl From<&object::common::critter::Perk> for i32 {
+    fn from(value: &object::common::critter::Perk) -> Self {
+        match value {
+            object::common::critter::Perk::Awareness => 1,
+            object::common::critter::Perk::BonusHtHAttacks => 2,
+            object::common::critter::Perk::BonusHtHDamage => 3,
+            object::common::critter::Perk::BonusMove => 4,
+            object::common::critter::Perk::BonusRangedDamage => 5,
+            object::common::critter::Perk::BonusRateOfFire => 6,
+            object::common::critter::Perk::EarlierSequence => 7,
+            object::common::critter::Perk::FasterHealing => 8,
+            object::common::critter::Perk::MoreCriticals => 9,
+            object::common::critter::Perk::NightVision => 10,
+            object::common::critter::Perk::Presence => 11,
+            object::common::critter::Perk::RadResistance => 12,
+            object::common::critter::Perk::Toughness => 13,
+            object::common::critter::Perk::StrongBack => 14,
+            object::common::critter::Perk::Sharpshooter => 15,
+            object::common::critter::Perk::SilentRunning => 16,
+            object::common::critter::Perk::Survivalist => 17,
+            object::common::critter::Perk::MasterTrader => 18,
+            object::common::critter::Perk::Educated => 19,
+            object::common::critter::Perk::Healer => 20,
+            object::common::critter::Perk::FortuneFinder => 21,
+            object::common::critter::Perk::BetterCriticals => 22,
+            object::common::critter::Perk::Empathy => 23,
+            object::common::critter::Perk::Slayer => 24,
+            object::common::critter::Perk::Sniper => 25,
+            object::common::critter::Perk::SilentDeath => 26,
+            object::common::critter::Perk::ActionBoy => 27,
+            object::common::critter::Perk::MentalBlock => 28,
+            object::common::critter::Perk::Lifegiver => 29,
+            object::common::critter::Perk::Dodger => 30,
+            object::common::critter::Perk::Snakeater => 31,
+            object::common::critter::Perk::MrFixit => 32,
+            object::common::critter::Perk::Medic => 33,
+            object::common::critter::Perk::MasterThief => 34,
+            object::common::critter::Perk::Speaker => 35,
+            object::common::critter::Perk::HeaveHo => 36,
+            object::common::critter::Perk::FriendlyFoe => 37,
+            object::common::critter::Perk::Pickpocket => 38,
+            object::common::critter::Perk::Ghost => 39,
+            object::common::critter::Perk::CultOfPersonality => 40,
+            object::common::critter::Perk::Scrounger => 41,
+            object::common::critter::Perk::Explorer => 42,
+            object::common::critter::Perk::FlowerChild => 43,
+            object::common::critter::Perk::Pathfinder => 44,
+            object::common::critter::Perk::AnimalFriend => 45,
+            object::common::critter::Perk::Scout => 46,
+            object::common::critter::Perk::MysteriousStranger => 47,
+            object::common::critter::Perk::Ranger => 48,
+            object::common::critter::Perk::QuickPockets => 49,
+            object::common::critter::Perk::SmoothTalker => 50,
+            object::common::critter::Perk::SwiftLearner => 51,
+            object::common::critter::Perk::Tag => 52,
+            object::common::critter::Perk::Mutate => 53,
+            object::common::critter::Perk::NukaColaAddiction => 54,
+            object::common::critter::Perk::BuffoutAddiction => 55,
+            object::common::critter::Perk::MentatsAddiction => 56,
+            object::common::critter::Perk::PsychoAddiction => 57,
+            object::common::critter::Perk::RadawayAddiction => 58,
+            object::common::critter::Perk::WeaponLongRange => 59,
+            object::common::critter::Perk::WeaponAccurate => 60,
+            object::common::critter::Perk::WeaponPenetrate => 61,
+            object::common::critter::Perk::WeaponKnockback => 62,
+            object::common::critter::Perk::PoweredArmor => 63,
+            object::common::critter::Perk::CombatArmor => 64,
+            object::common::critter::Perk::Unknown(value) => *value as i32,
+        }
+    }
+}
+
 impl TryFrom<&[u8; 4]> for object::common::map::Destination {
     type Error = errors::Error;
 
@@ -403,4 +813,20 @@ impl TryFrom<(u8, u16)> for Light {
             },
         })
     }
-}
\ No newline at end of file
+}
+impl From<&object::common::map::Destination> for [u8; 4] {
+    fn from(value: &object::common::map::Destination) -> Self {
+        let position = u32::from(&value.position).to_be_bytes();
+        [value.elevation.level.value * 2, position[1], position[2], position[3]]
+    }
+}
+
+impl From<&object::common::map::Map> for i32 {
+    fn from(value: &object::common::map::Map) -> Self {
+        match value {
+            object::common::map::Map::Current => -2,
+            object::common::map::Map::World => -1,
+            object::common::map::Map::Local(index) => *index as i32,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use std::io::Write;
+
+use byteorder::{BigEndian, WriteBytesExt};
+
+use crate::common::types::errors;
+
+use super::*;
+
+pub(crate) mod instance;
+pub(crate) mod flags;
+
+// Writes the prototype back as it was read: bits and words which aren't modelled are kept in `_` fields,
+// and the critters' damage type is present only when set, as in Fallout™ 2 prototypes.
+pub fn prototype<W: Write>(prototype: &Prototype, destination: &mut W) -> Result<(), errors::Error> {
+    if prototype.index > 0b1111_1111_1111 { return Err(errors::Error::Format); }
+
+    destination.write_u32::<BigEndian>(u32::from(&prototype.object) | prototype.index as u32)?;
+    destination.write_u32::<BigEndian>(prototype.meta.connections.description_id)?;
+    destination.write_u32::<BigEndian>(u32::from(&prototype.meta.sprite))?;
+
+    destination.write_u32::<BigEndian>(prototype.meta.light.distance.value as u32 | prototype.meta._light[0])?;
+    destination.write_u32::<BigEndian>(prototype.meta.light.intensity.value as u32 | prototype.meta._light[1])?;
+
+    flags::common(&prototype.meta.flags, prototype.meta._flags, destination)?;
+    instance::instance(&prototype.object, destination)
+}
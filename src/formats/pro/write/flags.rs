@@ -0,0 +1,39 @@
+use std::collections::HashSet;
+
+use errors::Error;
+use meta::info::flags::{Root, Transparency};
+
+use super::*;
+
+pub(crate) fn common<W: Write>(flags: &HashSet<Root>, rest: u32, destination: &mut W) -> Result<(), Error> {
+    destination.write_u32::<BigEndian>(bits(flags) | rest)?;
+    Ok(())
+}
+
+pub(crate) fn bits(flags: &HashSet<Root>) -> u32 {
+    let mut flags_bytes = [0u8; 4];
+
+    for flag in flags {
+        let (index, mask) = match flag {
+            Root::Flat => (3, 0x08),
+            Root::NotBlocking => (3, 0x10),
+            Root::MultiHex => (2, 0x08),
+            Root::NotBordered => (2, 0x10),
+            Root::Transparency(None) => (2, 0x80),
+            Root::Transparency(Some(Transparency::Red)) => (2, 0x40),
+            Root::Transparency(Some(Transparency::Wall)) => (1, 0x01),
+            Root::Transparency(Some(Transparency::Glass)) => (1, 0x02),
+            Root::Transparency(Some(Transparency::Steam)) => (1, 0x04),
+            Root::Transparency(Some(Transparency::Energy)) => (1, 0x08),
+            Root::Transparency(Some(Transparency::End)) => (0, 0x10),
+            Root::LightThrough => (0, 0x20),
+            Root::ShotThrough => (0, 0x80),
+            // these live in objects' extended flags on maps, not in prototypes
+            Root::Locked | Root::Jammed => continue,
+        };
+
+        flags_bytes[index] |= mask;
+    }
+
+    u32::from_be_bytes(flags_bytes)
+}
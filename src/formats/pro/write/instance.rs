@@ -0,0 +1,21 @@
+use super::*;
+
+mod common;
+
+mod item;
+pub(crate) mod critter;
+mod scenery;
+mod wall;
+mod tile;
+mod misc;
+
+pub(crate) fn instance<W: Write>(object: &ObjectInstance, destination: &mut W) -> Result<(), errors::Error> {
+    match object {
+        Type::Item(item) => item::instance(item, destination),
+        Type::Critter(critter) => critter::instance(critter, destination),
+        Type::Scenery(scenery) => scenery::instance(scenery, destination),
+        Type::Wall(wall) => wall::instance(wall, destination),
+        Type::Tile(tile) => tile::instance(tile, destination),
+        Type::Misc(misc) => misc::instance(misc, destination),
+    }
+}
@@ -0,0 +1,2 @@
+pub(crate) mod light;
+pub(crate) mod actions;
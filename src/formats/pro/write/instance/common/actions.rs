@@ -0,0 +1,13 @@
+use std::collections::HashSet;
+
+use object::common::actions::Instance;
+
+use super::super::super::*;
+
+pub(crate) fn bits(actions: &HashSet<Instance>) -> u8 {
+    actions.iter().fold(0u8, |bits, action| bits | match action {
+        Instance::PickUp => 0x80,
+        Instance::Usage(usage) => (if usage.itself { 0x08 } else { 0x00 }) | (if usage.something { 0x10 } else { 0x00 }),
+        Instance::Look | Instance::Talk => 0x00,
+    })
+}
@@ -0,0 +1,16 @@
+use std::collections::HashSet;
+
+use object::common::world::Light;
+
+use super::super::super::*;
+
+pub(crate) fn bits(light: &HashSet<Light>) -> u8 {
+    light.iter().fold(0u8, |bits, light| bits | match light {
+        Light::Vertical => 0x00,
+        Light::Horizontal => 0x08,
+        Light::NorthCorner => 0x10,
+        Light::SouthCorner => 0x20,
+        Light::EastCorner => 0x40,
+        Light::WestCorner => 0x80,
+    })
+}
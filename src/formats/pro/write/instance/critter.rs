@@ -0,0 +1,47 @@
+use object::critter::Instance;
+
+use super::super::*;
+
+pub(crate) mod flags;
+pub(crate) mod skills;
+pub(crate) mod statistics;
+
+pub(crate) fn instance<W: Write>(critter: &Instance, destination: &mut W) -> Result<(), errors::Error> {
+    destination.write_u32::<BigEndian>(critter._unknown)?;
+
+    destination.write_u32::<BigEndian>(critter.script.as_ref().map_or(0xFF_FF_FF_FF, u32::from))?;
+    destination.write_u32::<BigEndian>(critter.head.as_ref().map_or(0xFF_FF_FF_FF, u32::from))?;
+
+    destination.write_u32::<BigEndian>(critter.connections.ai_packet_id)?;
+    destination.write_u32::<BigEndian>(critter.team)?;
+
+    data(&object::critter::Data {
+        flags: critter.flags.clone(),
+        statistics: critter.statistics.clone(),
+        skills: critter.skills.clone(),
+        body: critter.body.clone(),
+        murder: critter.murder.clone(),
+        damage: critter.damage.clone(),
+        _flags: critter._flags,
+    }, destination)
+}
+
+// Part shared by prototypes and characters' records, damage type is written for Fallout™ 2 records only.
+pub(crate) fn data<W: Write>(data: &object::critter::Data, destination: &mut W) -> Result<(), errors::Error> {
+    flags::instance(&data.flags, data._flags, destination)?;
+
+    statistics::map(&data.statistics.basic, destination)?;
+    statistics::map(&data.statistics.bonuses, destination)?;
+
+    skills::map(&data.skills, destination)?;
+    destination.write_u32::<BigEndian>(u32::from(&data.body))?;
+
+    destination.write_u32::<BigEndian>(data.murder.experience)?;
+    destination.write_u32::<BigEndian>(u32::from(&data.murder.r#type))?;
+
+    if let Some(damage) = &data.damage {
+        destination.write_u32::<BigEndian>(u8::from(damage) as u32)?;
+    }
+
+    Ok(())
+}
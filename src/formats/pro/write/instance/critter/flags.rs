@@ -0,0 +1,34 @@
+use std::collections::HashSet;
+
+use object::critter::Flag;
+
+use super::super::super::*;
+
+pub(crate) fn instance<W: Write>(flags: &HashSet<Flag>, rest: u32, destination: &mut W) -> Result<(), errors::Error> {
+    destination.write_u32::<BigEndian>(bits(flags) | rest)?;
+    Ok(())
+}
+
+pub(crate) fn bits(flags: &HashSet<Flag>) -> u32 {
+    let mut flags_bytes = [0u8; 4];
+
+    for flag in flags {
+        let (index, mask) = match flag {
+            Flag::BarterAvailable => (3, 0x02),
+            Flag::NoSteal => (3, 0x20),
+            Flag::NoDrop => (3, 0x40),
+            Flag::NoLimbsLoose => (3, 0x80),
+            Flag::NoCorpseDisappear => (2, 0x01),
+            Flag::NoAutoHeal => (2, 0x02),
+            Flag::Invulnerable => (2, 0x04),
+            Flag::NoCorpse => (2, 0x08),
+            Flag::SpecialDeath => (2, 0x10),
+            Flag::RangedMelee => (2, 0x20),
+            Flag::NoKnockDown => (2, 0x40),
+        };
+
+        flags_bytes[index] |= mask;
+    }
+
+    u32::from_be_bytes(flags_bytes)
+}
@@ -0,0 +1,14 @@
+use std::collections::HashMap;
+
+use object::common::critter::Skill;
+
+use super::super::super::*;
+
+pub(crate) fn map<W: Write>(skills: &HashMap<Skill, u32>, destination: &mut W) -> Result<(), errors::Error> {
+    for index in 0..18 {
+        let skill = Skill::try_from(index)?;
+        destination.write_u32::<BigEndian>(skills.get(&skill).copied().unwrap_or(0))?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,15 @@
+use std::collections::HashMap;
+
+use object::common::critter::Statistic;
+
+use super::super::super::*;
+
+// Prototypes store the first 35 statistics, from Strength to Gender, absent ones are written as zeros.
+pub(crate) fn map<W: Write>(statistics: &HashMap<Statistic, i32>, destination: &mut W) -> Result<(), errors::Error> {
+    for index in 0..35 {
+        let statistic = Statistic::try_from(index)?;
+        destination.write_i32::<BigEndian>(statistics.get(&statistic).copied().unwrap_or(0))?;
+    }
+
+    Ok(())
+}
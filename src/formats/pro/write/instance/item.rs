@@ -0,0 +1,45 @@
+use object::item::{Flag, Instance, Type};
+use object::item::weapon;
+
+use super::common::actions;
+use super::super::*;
+
+mod r#type;
+
+pub(crate) fn instance<W: Write>(item: &Instance, destination: &mut W) -> Result<(), errors::Error> {
+    let mut flags_bytes = [0u8; 3];
+    let mut attack_modes = 0u8;
+
+    if item.flags.contains(&Flag::Hidden) { flags_bytes[0] |= 0x08; }
+    flags_bytes[2] |= actions::bits(&item.actions);
+
+    if let Type::Weapon(weapon) = &item.r#type {
+        for flag in &weapon.flags {
+            flags_bytes[2] |= match flag {
+                weapon::Flag::BigGun => 0x01,
+                weapon::Flag::SecondHand => 0x02,
+            };
+        }
+
+        for (index, attack) in weapon.attacks.iter().enumerate() {
+            if let Some(attack) = attack { attack_modes |= u8::from(&attack.mode) << (index * 4); }
+        }
+    }
+
+    let flags = u32::from_be_bytes([flags_bytes[0], flags_bytes[1], flags_bytes[2], attack_modes]);
+    destination.write_u32::<BigEndian>(flags | item._flags)?;
+
+    destination.write_u32::<BigEndian>(item.script.as_ref().map_or(0xFF_FF_FF_FF, u32::from))?;
+
+    destination.write_u32::<BigEndian>(r#type::id(&item.r#type))?;
+    destination.write_u32::<BigEndian>(u32::from(&item.material))?;
+
+    destination.write_u32::<BigEndian>(item.size)?;
+    destination.write_u32::<BigEndian>(item.weight)?;
+    destination.write_u32::<BigEndian>(item.price)?;
+
+    destination.write_u32::<BigEndian>(item.sprite.as_ref().map_or(0xFF_FF_FF_FF, u32::from))?;
+    destination.write_u8(item.connections._sounds_ids)?;
+
+    r#type::instance(&item.r#type, destination)
+}
@@ -0,0 +1,36 @@
+use object::item::Body;
+use object::item::Type::*;
+
+use super::super::super::*;
+
+mod armor;
+mod container;
+mod drug;
+mod weapon;
+mod ammo;
+mod misc;
+mod key;
+
+pub(crate) fn id(body: &Body) -> u32 {
+    match body {
+        Armor(_) => 0,
+        Container(_) => 1,
+        Drug(_) => 2,
+        Weapon(_) => 3,
+        Ammo(_) => 4,
+        Misc(_) => 5,
+        Key(_) => 6,
+    }
+}
+
+pub(crate) fn instance<W: Write>(body: &Body, destination: &mut W) -> Result<(), errors::Error> {
+    match body {
+        Armor(armor) => armor::instance(armor, destination),
+        Container(container) => container::instance(container, destination),
+        Drug(drug) => drug::instance(drug, destination),
+        Weapon(weapon) => weapon::instance(weapon, destination),
+        Ammo(ammo) => ammo::instance(ammo, destination),
+        Misc(misc) => misc::instance(misc, destination),
+        Key(key) => key::instance(key, destination),
+    }
+}
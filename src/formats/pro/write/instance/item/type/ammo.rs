@@ -0,0 +1,16 @@
+use object::item::ammo::Instance;
+
+use super::super::super::*;
+
+pub(crate) fn instance<W: Write>(ammo: &Instance, destination: &mut W) -> Result<(), errors::Error> {
+    destination.write_u32::<BigEndian>(ammo.caliber.as_ref().map_or(0, u32::from))?;
+    destination.write_u32::<BigEndian>(ammo.count)?;
+
+    destination.write_i32::<BigEndian>(ammo.adjustments.armor.class)?;
+    destination.write_i32::<BigEndian>(ammo.adjustments.armor.resistance)?;
+
+    destination.write_u32::<BigEndian>(ammo.adjustments.damage.multiplier)?;
+    destination.write_u32::<BigEndian>(ammo.adjustments.damage.divider)?;
+
+    Ok(())
+}
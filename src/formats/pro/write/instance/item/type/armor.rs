@@ -0,0 +1,39 @@
+use std::collections::HashMap;
+
+use object::common::combat::damage;
+use object::common::critter::Gender;
+use object::item::armor::Instance;
+
+use super::super::super::*;
+
+pub(crate) fn instance<W: Write>(armor: &Instance, destination: &mut W) -> Result<(), errors::Error> {
+    destination.write_u32::<BigEndian>(armor.class)?;
+
+    fn damage<W: Write>(values: &HashMap<damage::Type, u32>, destination: &mut W) -> Result<(), errors::Error> {
+        for r#type in [
+            damage::Type::Default,
+            damage::Type::Laser,
+            damage::Type::Fire,
+            damage::Type::Plasma,
+            damage::Type::Electrical,
+            damage::Type::Emp,
+            damage::Type::Explosive,
+        ] {
+            destination.write_u32::<BigEndian>(values.get(&r#type).copied().unwrap_or(0))?;
+        }
+
+        Ok(())
+    }
+
+    damage(&armor.resistance, destination)?;
+    damage(&armor.threshold, destination)?;
+
+    destination.write_i32::<BigEndian>(armor.perk.as_ref().map_or(-1, i32::from))?;
+
+    for gender in [Gender::Male, Gender::Female] {
+        let sprite = armor.appearance.sprites.get(&gender).ok_or(errors::Error::Format)?;
+        destination.write_u32::<BigEndian>(u32::from(sprite))?;
+    }
+
+    Ok(())
+}
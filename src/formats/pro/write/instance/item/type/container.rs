@@ -0,0 +1,14 @@
+use object::item::container::{Flag, Instance};
+
+use super::super::super::*;
+
+pub(crate) fn instance<W: Write>(container: &Instance, destination: &mut W) -> Result<(), errors::Error> {
+    destination.write_u32::<BigEndian>(container.size)?;
+
+    let mut flags_bytes = [0u8; 4];
+    if container.flags.contains(&Flag::NoPickUp) { flags_bytes[3] |= 0x01; }
+    if container.flags.contains(&Flag::MagicHands) { flags_bytes[3] |= 0x08; }
+
+    destination.write_u32::<BigEndian>(u32::from_be_bytes(flags_bytes) | container._flags)?;
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use std::time::Duration;
+
+use object::item::drug::{Amount, Effect, Instance};
+
+use super::super::super::*;
+
+type Slots = [Option<(i32, [i32; 3])>; 3];
+
+// Up to three statistics fit, a randomized one takes an additional slot before it, holding the minimums.
+pub(crate) fn instance<W: Write>(drug: &Instance, destination: &mut W) -> Result<(), errors::Error> {
+    let unknown = &drug._unknown;
+    let recorded = |statistic: i32| unknown.statistics.iter().position(|value| *value == statistic);
+
+    let mut effects: Vec<_> = drug.effects.iter()
+        .map(|(statistic, effects)| (i32::from(*statistic), effects))
+        .collect();
+    effects.sort_by_key(|(statistic, _)| (recorded(*statistic).unwrap_or(usize::MAX), *statistic));
+
+    let mut durations: Option<[Option<Duration>; 2]> = None;
+    for (_, effects) in &effects {
+        let delays = [effects[1].delay, effects[2].delay];
+        if *durations.get_or_insert(delays) != delays { return Err(errors::Error::Format); }
+    }
+
+    // statistics stay in the slots they were read from, unless edits leave no room for that
+    let slots = layout(&effects, recorded).or_else(|| layout(&effects, |_| None)).ok_or(errors::Error::Format)?;
+
+    let mut statistics = [-1i32; 3];
+    let mut amounts = unknown.amounts;
+
+    for (slot, value) in slots.iter().enumerate() {
+        match value {
+            Some((statistic, values)) => {
+                statistics[slot] = *statistic;
+                for (index, value) in values.iter().enumerate() { amounts[index][slot] = *value; }
+            }
+            // an unused slot marked as random would turn the following statistic random
+            None => statistics[slot] = match unknown.statistics[slot] {
+                -2 if matches!(slots.get(slot + 1), Some(Some((statistic, _))) if *statistic >= 0) => -1,
+                value if value < 0 => value,
+                _ => -1,
+            },
+        }
+    }
+
+    fn minutes(duration: &Option<Duration>) -> u32 {
+        duration.map_or(0, |duration| (duration.as_secs() / 60) as u32)
+    }
+
+    let durations = durations.map_or(unknown.durations, |durations| [minutes(&durations[0]), minutes(&durations[1])]);
+
+    for statistic in statistics { destination.write_i32::<BigEndian>(statistic)?; }
+    for amount in amounts[0] { destination.write_i32::<BigEndian>(amount)?; }
+
+    destination.write_u32::<BigEndian>(durations[0])?;
+    for amount in amounts[1] { destination.write_i32::<BigEndian>(amount)?; }
+
+    destination.write_u32::<BigEndian>(durations[1])?;
+    for amount in amounts[2] { destination.write_i32::<BigEndian>(amount)?; }
+
+    let addiction = drug.addiction.as_ref();
+
+    destination.write_u32::<BigEndian>(addiction.map_or(unknown.addiction[0], |a| a.chance.value as u32))?;
+    destination.write_i32::<BigEndian>(addiction.map_or(-1, |a| i32::from(&a.perk)))?;
+    destination.write_u32::<BigEndian>(addiction.map_or(unknown.addiction[1], |a| minutes(&Some(a.delay))))?;
+
+    Ok(())
+}
+
+// Places statistics into their preferred slots when free, or into the first free ones.
+fn layout<F: Fn(i32) -> Option<usize>>(effects: &[(i32, &[Effect; 3])], preferred: F) -> Option<Slots> {
+    let mut slots: Slots = [None; 3];
+
+    for (statistic, effects) in effects {
+        let random = effects.iter().any(|effect| matches!(effect.impact, Amount::Random(_)));
+        let free = |slot: &usize| *slot >= random as usize && slots[*slot].is_none() &&
+            (!random || slots[*slot - 1].is_none());
+
+        let slot = preferred(*statistic).filter(&free).or_else(|| (0..slots.len()).find(&free))?;
+
+        let mut minimums = [0i32; 3];
+        let mut maximums = [0i32; 3];
+
+        for (index, effect) in effects.iter().enumerate() {
+            (minimums[index], maximums[index]) = match &effect.impact {
+                Amount::Fixed(value) => (*value, *value),
+                Amount::Random(range) => (*range.start(), *range.end()),
+            };
+        }
+
+        if random { slots[slot - 1] = Some((-2, minimums)); }
+        slots[slot] = Some((*statistic, maximums));
+    }
+
+    Some(slots)
+}
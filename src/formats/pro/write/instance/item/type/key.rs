@@ -0,0 +1,8 @@
+use object::item::key::Instance;
+
+use super::super::super::*;
+
+pub(crate) fn instance<W: Write>(key: &Instance, destination: &mut W) -> Result<(), errors::Error> {
+    destination.write_i32::<BigEndian>(key.code.map_or(-1, |code| code as i32))?;
+    Ok(())
+}
@@ -0,0 +1,11 @@
+use object::item::misc::Instance;
+
+use super::super::super::*;
+
+pub(crate) fn instance<W: Write>(misc: &Instance, destination: &mut W) -> Result<(), errors::Error> {
+    destination.write_i32::<BigEndian>(misc.connections.power_item_id.map_or(-1, |pid| pid as i32))?;
+    destination.write_u32::<BigEndian>(misc.caliber.as_ref().map_or(0, u32::from))?;
+    destination.write_u32::<BigEndian>(misc.count)?;
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use object::item::weapon::Instance;
+
+use super::super::super::*;
+
+pub(crate) fn instance<W: Write>(weapon: &Instance, destination: &mut W) -> Result<(), errors::Error> {
+    destination.write_u32::<BigEndian>(weapon.animation.as_ref().map_or(0x00, u32::from))?;
+
+    destination.write_u32::<BigEndian>(*weapon.damage.value.start())?;
+    destination.write_u32::<BigEndian>(*weapon.damage.value.end())?;
+    destination.write_u32::<BigEndian>(u8::from(&weapon.damage.r#type) as u32)?;
+
+    for (attack, unknown) in weapon.attacks.iter().zip(&weapon._unknown.attacks) {
+        destination.write_u32::<BigEndian>(attack.as_ref().map_or(unknown[0], |attack| *attack.range.end()))?;
+    }
+
+    match weapon.connections.projectile_misc_id {
+        None => {
+            destination.write_u16::<BigEndian>(weapon._unknown.projectile)?;
+            destination.write_u16::<BigEndian>(0xFF_FF)?;
+        }
+        Some(index) => {
+            destination.write_u16::<BigEndian>(0x0500)?;
+            destination.write_u16::<BigEndian>(index)?;
+        }
+    }
+
+    destination.write_u32::<BigEndian>(weapon.requirements.strength)?;
+
+    for (attack, unknown) in weapon.attacks.iter().zip(&weapon._unknown.attacks) {
+        destination.write_u32::<BigEndian>(attack.as_ref().map_or(unknown[1], |attack| attack.cost))?;
+    }
+
+    destination.write_i32::<BigEndian>(weapon.connections.failure_list_id.map_or(-1, |id| id as i32))?;
+    destination.write_i32::<BigEndian>(weapon.perk.as_ref().map_or(-1, i32::from))?;
+
+    let ammunition = weapon.ammunition.as_ref();
+
+    destination.write_u32::<BigEndian>(ammunition.map_or(weapon._unknown.rounds[0], |a| a.rounds.burst))?;
+    destination.write_u32::<BigEndian>(ammunition.map_or(0, |a| u32::from(&a.caliber)))?;
+
+    destination.write_i32::<BigEndian>(weapon.connections.ammo_item_id.map_or(-1, |id| id as i32))?;
+
+    destination.write_u32::<BigEndian>(ammunition.map_or(weapon._unknown.rounds[1], |a| a.rounds.magazine))?;
+    destination.write_u8(weapon.connections._sounds_ids)?;
+
+    Ok(())
+}
@@ -0,0 +1,8 @@
+use object::misc::Instance;
+
+use super::super::*;
+
+pub(crate) fn instance<W: Write>(misc: &Instance, destination: &mut W) -> Result<(), errors::Error> {
+    destination.write_u32::<BigEndian>(misc._unknown)?;
+    Ok(())
+}
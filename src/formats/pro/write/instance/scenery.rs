@@ -0,0 +1,19 @@
+use object::scenery::Instance;
+
+use super::common::{actions, light};
+use super::super::*;
+
+mod r#type;
+
+pub(crate) fn instance<W: Write>(scenery: &Instance, destination: &mut W) -> Result<(), errors::Error> {
+    let flags = u32::from_be_bytes([light::bits(&scenery.light), 0, 0, actions::bits(&scenery.actions)]);
+    destination.write_u32::<BigEndian>(flags | scenery._flags)?;
+
+    destination.write_u32::<BigEndian>(scenery.script.as_ref().map_or(0xFF_FF_FF_FF, u32::from))?;
+
+    destination.write_u32::<BigEndian>(r#type::id(&scenery.body))?;
+    destination.write_u32::<BigEndian>(u32::from(&scenery.material))?;
+    destination.write_u8(scenery.connections._sounds_ids)?;
+
+    r#type::body(&scenery.body, destination)
+}
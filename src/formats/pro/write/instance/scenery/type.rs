@@ -0,0 +1,33 @@
+use crate::formats::pro::object::scenery::ladder::Direction::*;
+use crate::formats::pro::object::scenery::SceneryType::*;
+
+use super::super::*;
+
+mod door;
+mod stairs;
+mod elevator;
+mod ladder;
+mod generic;
+
+pub(crate) fn id(body: &object::scenery::Body) -> u32 {
+    match body {
+        Door(_) => 0,
+        Stairs(_) => 1,
+        Elevator(_) => 2,
+        Ladder(ladder) => match ladder.direction {
+            Bottom => 3,
+            Top => 4,
+        },
+        Generic(_) => 5,
+    }
+}
+
+pub(crate) fn body<W: Write>(body: &object::scenery::Body, destination: &mut W) -> Result<(), errors::Error> {
+    match body {
+        Door(door) => door::instance(door, destination),
+        Stairs(stairs) => stairs::instance(stairs, destination),
+        Elevator(elevator) => elevator::instance(elevator, destination),
+        Ladder(ladder) => ladder::instance(ladder, destination),
+        Generic(generic) => generic::instance(generic, destination),
+    }
+}
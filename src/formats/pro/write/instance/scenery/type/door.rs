@@ -0,0 +1,11 @@
+use super::super::super::*;
+
+pub(crate) fn instance<W: Write>(door: &object::scenery::door::Instance, destination: &mut W) -> Result<(), errors::Error> {
+    let mut flags_bytes = [0u8; 4];
+    if door.flags.contains(&object::scenery::door::Flag::Passable) { flags_bytes[3] |= 0x0F; }
+
+    destination.write_u32::<BigEndian>(u32::from_be_bytes(flags_bytes) | door._flags)?;
+    destination.write_u32::<BigEndian>(door._unknown)?;
+
+    Ok(())
+}
@@ -0,0 +1,8 @@
+use super::super::super::*;
+
+pub(crate) fn instance<W: Write>(elevator: &object::scenery::elevator::Instance, destination: &mut W) -> Result<(), errors::Error> {
+    destination.write_i32::<BigEndian>(elevator.r#type.map_or(-1, |value| value as i32))?;
+    destination.write_i32::<BigEndian>(elevator.floor)?;
+
+    Ok(())
+}
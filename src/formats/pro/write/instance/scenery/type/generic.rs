@@ -0,0 +1,6 @@
+use super::super::super::*;
+
+pub(crate) fn instance<W: Write>(generic: &object::scenery::generic::Instance, destination: &mut W) -> Result<(), errors::Error> {
+    destination.write_u32::<BigEndian>(generic._unknown)?;
+    Ok(())
+}
@@ -0,0 +1,8 @@
+use super::super::super::*;
+
+pub(crate) fn instance<W: Write>(ladder: &object::scenery::ladder::Instance, destination: &mut W) -> Result<(), errors::Error> {
+    let destination_bytes = ladder.destination.as_ref().map_or([0xFF, 0xFF, 0xFF, 0xFF], <[u8; 4]>::from);
+    destination.write_all(&destination_bytes)?;
+
+    Ok(())
+}
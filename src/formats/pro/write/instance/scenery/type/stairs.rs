@@ -0,0 +1,10 @@
+use super::super::super::*;
+
+pub(crate) fn instance<W: Write>(stairs: &object::scenery::stairs::Instance, destination: &mut W) -> Result<(), errors::Error> {
+    let destination_bytes = stairs.destination.target.as_ref().map_or([0xFF, 0xFF, 0xFF, 0xFF], <[u8; 4]>::from);
+
+    destination.write_all(&destination_bytes)?;
+    destination.write_i32::<BigEndian>(i32::from(&stairs.destination.map))?;
+
+    Ok(())
+}
@@ -0,0 +1,8 @@
+use object::tile::Instance;
+
+use super::super::*;
+
+pub(crate) fn instance<W: Write>(tile: &Instance, destination: &mut W) -> Result<(), errors::Error> {
+    destination.write_u32::<BigEndian>(u32::from(&tile.material))?;
+    Ok(())
+}
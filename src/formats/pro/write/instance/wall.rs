@@ -0,0 +1,14 @@
+use object::wall::Instance;
+
+use super::common::{actions, light};
+use super::super::*;
+
+pub(crate) fn instance<W: Write>(wall: &Instance, destination: &mut W) -> Result<(), errors::Error> {
+    let flags = u32::from_be_bytes([light::bits(&wall.light), 0, 0, actions::bits(&wall.actions)]);
+    destination.write_u32::<BigEndian>(flags | wall._flags)?;
+
+    destination.write_u32::<BigEndian>(wall.script.as_ref().map_or(0xFF_FF_FF_FF, u32::from))?;
+    destination.write_u32::<BigEndian>(u32::from(&wall.material))?;
+
+    Ok(())
+}
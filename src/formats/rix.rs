@@ -0,0 +1,11 @@
+use crate::formats::{frm, pal};
+
+pub mod parse;
+pub mod write;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Image {
+    pub palette: pal::Palette,
+    pub frame: frm::Frame,
+}
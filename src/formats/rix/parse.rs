@@ -0,0 +1,40 @@
+use std::io::{Cursor, Read, Seek, SeekFrom};
+
+use byteorder::{LittleEndian, ReadBytesExt};
+
+use crate::common::types::errors::Error;
+use crate::formats::{frm, pal};
+
+use super::Image;
+
+pub fn image<S: Read + Seek>(source: &mut S) -> Result<Image, Error> {
+    source.seek(SeekFrom::Start(0))?;
+
+    let mut signature = [0u8; 4];
+    source.read_exact(&mut signature)?;
+
+    if &signature != b"RIX3" { return Err(Error::Format); }
+
+    let width = source.read_u16::<LittleEndian>()?;
+    let height = source.read_u16::<LittleEndian>()?;
+
+    if source.read_u8()? != 0xAF { return Err(Error::Format); }
+    if source.read_u8()? != 0x00 { return Err(Error::Format); }
+
+    let mut palette = vec![0u8; 768];
+    source.read_exact(&mut palette)?;
+
+    let palette = pal::parse::palette(&mut Cursor::new(palette))?;
+
+    let mut indexes = vec![0u8; width as usize * height as usize];
+    source.read_exact(&mut indexes)?;
+
+    Ok(Image {
+        palette,
+        frame: frm::Frame {
+            size: frm::Size { width, height },
+            shift: frm::Shift { x: 0, y: 0 },
+            indexes,
+        },
+    })
+}
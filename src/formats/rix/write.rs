@@ -0,0 +1,43 @@
+use std::io::Write;
+
+use byteorder::{LittleEndian, WriteBytesExt};
+
+use crate::common::types::errors::Error;
+
+use super::Image;
+
+pub fn image<W: Write>(image: &Image, destination: &mut W) -> Result<(), Error> {
+    let size = &image.frame.size;
+
+    if image.frame.indexes.len() != size.width as usize * size.height as usize {
+        return Err(Error::Format);
+    }
+
+    destination.write_all(b"RIX3")?;
+    destination.write_u16::<LittleEndian>(size.width)?;
+    destination.write_u16::<LittleEndian>(size.height)?;
+    destination.write_u8(0xAF)?;
+    destination.write_u8(0x00)?;
+
+    for color in &image.palette.colors {
+        match color {
+            Some(pixel) => {
+                for component in [&pixel.red, &pixel.green, &pixel.blue] {
+                    let (start, end) = (*component.scale.start() as u32, *component.scale.end() as u32);
+
+                    if !component.scale.contains(&component.value) || end <= start {
+                        return Err(Error::Format);
+                    }
+
+                    let value = (component.value as u32 - start) * 63 / (end - start);
+                    destination.write_u8(value as u8)?;
+                }
+            }
+            None => destination.write_all(&[u8::MAX; 3])?,
+        }
+    }
+
+    destination.write_all(&image.frame.indexes)?;
+
+    Ok(())
+}
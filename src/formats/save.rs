@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::common::types::geometry::Coordinate;
+use crate::formats::frm;
+use crate::formats::map::blueprint::prototype;
+use crate::formats::pro::object;
+use crate::formats::pro::object::common::critter::{Perk, Skill, Trait};
+
+pub mod parse;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Save {
+    pub header: Header,
+    pub dude: u32,
+    pub variables: Vec<i32>,
+    pub maps: Maps,
+    pub player: Player,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::common::sorted::map"))]
+    pub kills: HashMap<object::critter::murder::Type, u32>,
+    pub tagged: Vec<Skill>,
+    pub party: Vec<party::Member>, // Fallout™ 2 only, the player comes first
+    pub state: Option<State>, // Fallout™ 2 only, missing in saves made during combat
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Header {
+    pub version: (u16, u16),
+    pub name: String,
+    pub description: String,
+    pub date: header::Date,
+    pub location: header::Location,
+    pub thumbnail: frm::Frame,
+}
+
+pub mod header {
+    use crate::common::types::space::Elevation;
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Real {
+        pub day: u16,
+        pub month: u16,
+        pub year: u16,
+        pub time: u32,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Game {
+        pub day: u16,
+        pub month: u16,
+        pub year: u16,
+        pub ticks: u32,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Date {
+        pub real: Real,
+        pub game: Game,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Location {
+        pub map: u16,
+        pub filename: String,
+        pub elevation: Elevation,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Maps {
+    pub files: Vec<String>,
+    pub automap: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Player {
+    pub object: prototype::Instance,
+    pub center: Option<Coordinate<u8, Range<u8>>>,
+    pub sneaking: bool,
+    pub critter: object::critter::Data,
+}
+
+pub mod party {
+    use std::collections::HashMap;
+
+    use crate::common::types::models::Identifier;
+    use crate::formats::pro::ObjectType;
+
+    use super::Perk;
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Member {
+        pub prototype: Identifier<ObjectType>,
+        #[cfg_attr(feature = "serde", serde(
+            serialize_with = "crate::common::sorted::pairs",
+            deserialize_with = "crate::common::sorted::from_pairs",
+        ))]
+        pub perks: HashMap<Perk, u32>,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    pub ai: Vec<[i32; 7]>, // AI packet settings of party members following the player, not modelled
+    pub statistics: state::Statistics,
+    pub traits: Vec<Trait>,
+}
+
+pub mod state {
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Statistics {
+        pub points: u32,
+        pub level: u32,
+        pub experience: u32,
+        pub reputation: i32,
+        pub karma: i32,
+    }
+}
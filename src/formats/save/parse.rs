@@ -0,0 +1,286 @@
+use std::collections::HashMap;
+use std::io::{Cursor, Read, Seek, SeekFrom};
+
+use byteorder::{BigEndian, ReadBytesExt};
+use flate2::read::GzDecoder;
+
+use crate::common::traits::TryFromOptional;
+use crate::common::types::errors::Error;
+use crate::common::types::geometry::Coordinate;
+use crate::common::types::models::Identifier;
+use crate::common::types::space::Elevation;
+use crate::common::types::version::Version;
+use crate::formats::{frm, map};
+use crate::formats::map::parse::{prototypes::prototype, Provider};
+use crate::formats::pro::{object, ObjectType};
+use crate::formats::pro::parse::instance::critter;
+
+use super::*;
+
+const THUMBNAIL_WIDTH: u16 = 224;
+const THUMBNAIL_HEIGHT: u16 = 133;
+
+const KILL_TYPES_FIRST: u32 = 15; // Fallout™ 1 lacks geckos, aliens, giant ants and the big bad boss
+const KILL_TYPES_SECOND: u32 = 19;
+const TAGGED_SKILLS: usize = 4;
+const PERKS: i32 = 119;
+const TRAITS: usize = 2;
+
+const MAP_NAME_LENGTH: usize = 16;
+
+// `party` lists prototypes of party members as defined in `data/party.txt`, see `party`.
+// Count of global variables is found from the file itself, they are stored twice around the list of maps.
+pub fn save<S: Read + Seek, P: Provider>(source: &mut S, provider: &P, version: &Version,
+                                         party: &[Identifier<ObjectType>]) -> Result<Save, Error> {
+    source.seek(SeekFrom::Start(0))?;
+
+    let header = header(source)?;
+
+    let dude = source.read_u32::<BigEndian>()?;
+    let (variables, maps) = self::variables(source)?;
+
+    let object = prototype::instance(source, provider, version)?;
+    let center = u32::try_from(source.read_i32::<BigEndian>()?)
+        .ok()
+        .map_or(Ok(None), |v| Coordinate::try_from(v).map(Some))?;
+
+    let sneaking = source.read_u32::<BigEndian>()? != 0;
+    let critter = critter::data(source, version)?;
+
+    let kill_types = match version {
+        Version::First => KILL_TYPES_FIRST,
+        Version::Second => KILL_TYPES_SECOND,
+    };
+
+    let mut kills = HashMap::new();
+    for index in 0..kill_types {
+        kills.insert(object::critter::murder::Type::try_from(index)?, source.read_u32::<BigEndian>()?);
+    }
+
+    let mut tagged = Vec::new();
+    for _ in 0..TAGGED_SKILLS {
+        if let Some(skill) = object::common::critter::Skill::try_from_optional(source.read_i32::<BigEndian>()?, -1)? {
+            tagged.push(skill);
+        }
+    }
+
+    let (party, state) = match version {
+        Version::First => (Vec::new(), None),
+        Version::Second => {
+            let members = self::party_members(source, party)?;
+            let state = self::state(source, party.len().saturating_sub(1))?;
+
+            (members, state)
+        }
+    };
+
+    Ok(Save {
+        header,
+        dude,
+        variables,
+        maps,
+        player: Player { object, center, sneaking, critter },
+        kills,
+        tagged,
+        party,
+        state,
+    })
+}
+
+// Reads prototypes' identifiers of `[Party Member N]` sections of `party.txt`, the player comes first.
+pub fn party<S: Read + Seek>(source: &mut S) -> Result<Vec<Identifier<ObjectType>>, Error> {
+    source.seek(SeekFrom::Start(0))?;
+
+    let mut bytes = Vec::new();
+    source.read_to_end(&mut bytes)?;
+
+    let text = std::str::from_utf8(&bytes).map_err(|_| Error::Format)?;
+    let mut members = Vec::new();
+    let mut section = false;
+
+    for line in text.lines() {
+        let line = line.split(';').next().unwrap_or_default().trim();
+
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            section = name.trim().to_ascii_lowercase().starts_with("party member");
+            continue;
+        }
+
+        if !section { continue; }
+
+        if let Some((key, value)) = line.split_once('=') {
+            if key.trim().eq_ignore_ascii_case("party_member_pid") {
+                members.push(Identifier::try_from(value.trim().parse::<u32>().map_err(|_| Error::Format)?)?);
+            }
+        }
+    }
+
+    Ok(members)
+}
+
+// Per-map `.sav` files of a save slot, named by `Maps::files`, are maps, usually gzip-compressed.
+pub fn map<S: Read, P: Provider>(source: &mut S, provider: &P) -> Result<map::Map, Error> {
+    let mut bytes = Vec::new();
+    source.read_to_end(&mut bytes)?;
+
+    if bytes.starts_with(&[0x1F, 0x8B]) {
+        let mut decompressed = Vec::new();
+        GzDecoder::new(bytes.as_slice()).read_to_end(&mut decompressed)?;
+        bytes = decompressed;
+    }
+
+    map::parse::map(&mut Cursor::new(bytes), provider)
+}
+
+fn string<S: Read>(source: &mut S, length: usize) -> Result<String, Error> {
+    let mut bytes = vec![0u8; length];
+    source.read_exact(&mut bytes)?;
+
+    let end = bytes.iter().position(|b| *b == 0).unwrap_or(length);
+    Ok(String::from(std::str::from_utf8(&bytes[..end]).map_err(|_| Error::Format)?))
+}
+
+fn header<S: Read + Seek>(source: &mut S) -> Result<Header, Error> {
+    if string(source, 24)? != "FALLOUT SAVE FILE" { return Err(Error::Format); }
+
+    let version = (source.read_u16::<BigEndian>()?, source.read_u16::<BigEndian>()?);
+    if source.read_u8()? != b'R' { return Err(Error::Format); }
+
+    let name = string(source, 32)?;
+    let description = string(source, 30)?;
+
+    let real = header::Real {
+        day: source.read_u16::<BigEndian>()?,
+        month: source.read_u16::<BigEndian>()?,
+        year: source.read_u16::<BigEndian>()?,
+        time: source.read_u32::<BigEndian>()?,
+    };
+
+    let game = header::Game {
+        month: source.read_u16::<BigEndian>()?,
+        day: source.read_u16::<BigEndian>()?,
+        year: source.read_u16::<BigEndian>()?,
+        ticks: source.read_u32::<BigEndian>()?,
+    };
+
+    let elevation = Elevation::try_from(source.read_u16::<BigEndian>()? as u32)?;
+    let map = source.read_u16::<BigEndian>()?;
+    let filename = string(source, 16)?;
+
+    let mut indexes = vec![0u8; THUMBNAIL_WIDTH as usize * THUMBNAIL_HEIGHT as usize];
+    source.read_exact(&mut indexes)?;
+
+    source.seek(SeekFrom::Current(128))?;
+
+    Ok(Header {
+        version,
+        name,
+        description,
+        date: header::Date { real, game },
+        location: header::Location { map, filename, elevation },
+        thumbnail: frm::Frame {
+            size: frm::Size { width: THUMBNAIL_WIDTH, height: THUMBNAIL_HEIGHT },
+            shift: frm::Shift { x: 0, y: 0 },
+            indexes,
+        },
+    })
+}
+
+fn variables<S: Read + Seek>(source: &mut S) -> Result<(Vec<i32>, Maps), Error> {
+    let mut variables = Vec::new();
+
+    loop {
+        let position = source.stream_position()?;
+
+        if let Ok(maps) = self::maps(source) {
+            let mut copy = Vec::new();
+            for _ in 0..variables.len() {
+                match source.read_i32::<BigEndian>() {
+                    Ok(value) => copy.push(value),
+                    Err(_) => break,
+                }
+            }
+
+            if copy == variables { return Ok((variables, maps)); }
+        }
+
+        source.seek(SeekFrom::Start(position))?;
+        variables.push(source.read_i32::<BigEndian>()?);
+    }
+}
+
+fn maps<S: Read>(source: &mut S) -> Result<Maps, Error> {
+    let count = source.read_u32::<BigEndian>()?;
+    if count == 0 { return Err(Error::Format); }
+
+    let mut files = Vec::new();
+
+    for _ in 0..count {
+        let mut bytes = Vec::new();
+
+        loop {
+            match source.read_u8()? {
+                0 => break,
+                _ if bytes.len() == MAP_NAME_LENGTH => return Err(Error::Format),
+                byte => bytes.push(byte),
+            }
+        }
+
+        let file = String::from_utf8(bytes).map_err(|_| Error::Format)?;
+        if !file.to_ascii_uppercase().ends_with(".SAV") { return Err(Error::Format); }
+
+        files.push(file);
+    }
+
+    let automap = source.read_u32::<BigEndian>()?;
+
+    Ok(Maps { files, automap })
+}
+
+fn party_members<S: Read>(source: &mut S, party: &[Identifier<ObjectType>]) -> Result<Vec<party::Member>, Error> {
+    let mut members = Vec::new();
+
+    for prototype in party {
+        let mut perks = HashMap::new();
+
+        for index in 0..PERKS {
+            let rank = source.read_u32::<BigEndian>()?;
+            if rank > 0 {
+                perks.insert(Perk::try_from(index).unwrap_or(Perk::Unknown(index as u32)), rank);
+            }
+        }
+
+        members.push(party::Member { prototype: prototype.clone(), perks });
+    }
+
+    Ok(members)
+}
+
+fn state<S: Read>(source: &mut S, followers: usize) -> Result<Option<State>, Error> {
+    let combat = source.read_u32::<BigEndian>()?;
+    if combat & 0x01 != 0 { return Ok(None); }
+
+    let mut ai = Vec::new();
+    for _ in 0..followers {
+        let mut settings = [0i32; 7];
+        source.read_i32_into::<BigEndian>(&mut settings)?;
+        ai.push(settings);
+    }
+
+    let statistics = state::Statistics {
+        points: source.read_u32::<BigEndian>()?,
+        level: source.read_u32::<BigEndian>()?,
+        experience: source.read_u32::<BigEndian>()?,
+        reputation: source.read_i32::<BigEndian>()?,
+        karma: source.read_i32::<BigEndian>()?,
+    };
+
+    let mut traits = Vec::new();
+    for _ in 0..TRAITS {
+        if let Some(r#trait) = Trait::try_from_optional(source.read_i32::<BigEndian>()?, -1)? {
+            traits.push(r#trait);
+        }
+    }
+
+    Ok(Some(State { ai, statistics, traits }))
+}
@@ -0,0 +1,216 @@
+use std::collections::HashMap;
+
+pub mod parse;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Worldmap {
+    pub columns: u32,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::common::sorted::map"))]
+    pub terrains: HashMap<String, u32>,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::common::sorted::map"))]
+    pub frequencies: HashMap<encounter::Frequency, u32>,
+    pub tiles: Vec<tile::Instance>,
+    pub encounters: Vec<encounter::Table>,
+    pub groups: Vec<encounter::Group>,
+    pub random: Vec<encounter::Random>,
+}
+
+pub mod tile {
+    use super::encounter::Frequency;
+
+    pub const COLUMNS: u8 = 7;
+    pub const ROWS: u8 = 6;
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Subtile {
+        pub x: u8,
+        pub y: u8,
+        pub terrain: String,
+        pub fill: String,
+        pub chances: [Frequency; 3], // morning, afternoon, night
+        pub table: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Instance {
+        pub index: u32,
+        pub art: u32,
+        pub difficulty: i32,
+        pub walkmask: Option<String>,
+        pub subtiles: Vec<Subtile>,
+    }
+}
+
+pub mod encounter {
+    use std::ops::RangeInclusive;
+
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum Frequency {
+        None,
+        Rare,
+        Uncommon,
+        Common,
+        Frequent,
+        Forced,
+    }
+
+    // `[Encounter Table N]` sections, picked by subtiles.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Table {
+        pub index: u32,
+        pub lookup: String,
+        pub maps: Vec<String>,
+        pub entries: Vec<Entry>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Entry {
+        pub chance: u32, // percents
+        pub counter: Option<u32>,
+        pub special: bool,
+        pub map: Option<String>,
+        pub spawns: Vec<Spawn>,
+        pub conditions: Vec<String>, // `If(...)` expressions and unrecognised parts as written
+    }
+
+    #[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum Relation {
+        And,
+        Fighting,
+    }
+
+    // One `(min-max) GROUP [AMBUSH TARGET]` part of an entry's `enc:`, related to the previous one.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Spawn {
+        pub count: RangeInclusive<u32>,
+        pub group: String,
+        pub relation: Option<Relation>,
+        pub ambush: Option<String>, // whom the group ambushes, e.g. `Player`
+    }
+
+    // `[Encounter: NAME]` sections, spawned by entries of tables.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Group {
+        pub name: String,
+        pub position: Option<String>,
+        pub members: Vec<group::Member>,
+    }
+
+    pub mod group {
+        use std::ops::RangeInclusive;
+
+        use crate::common::types::models::Identifier;
+        use crate::formats::pro::ObjectType;
+
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub struct Item {
+            pub count: RangeInclusive<u32>,
+            pub prototype: Identifier<ObjectType>,
+            pub wielded: bool,
+        }
+
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub struct Member {
+            pub ratio: Option<u32>, // percents
+            pub prototype: Option<Identifier<ObjectType>>,
+            pub dead: bool,
+            pub items: Vec<Item>,
+            pub script: Option<u32>,
+            pub conditions: Vec<String>, // `If(...)` expressions and unrecognised parts as written
+        }
+    }
+
+    // `[Random Maps: TERRAIN]` sections, maps used for encounters on a terrain.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Random {
+        pub terrain: String,
+        pub maps: Vec<String>,
+    }
+}
+
+pub mod city {
+    use std::ops::Range;
+
+    use crate::common::types::geometry::Coordinate;
+
+    #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum Size {
+        Small,
+        Medium,
+        Large,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Entrance {
+        pub visible: bool,
+        pub position: (u32, u32),
+        pub map: String,
+        pub elevation: Option<u32>,
+        pub tile: Option<Coordinate<u8, Range<u8>>>,
+        pub orientation: i32,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Area {
+        pub index: u32,
+        pub name: String,
+        pub position: (u32, u32),
+        pub visible: bool,
+        pub locked: bool,
+        pub size: Size,
+        pub townmap: Option<u32>,
+        pub label: Option<u32>,
+        pub entrances: Vec<Entrance>,
+    }
+}
+
+pub mod maps {
+    use std::ops::Range;
+
+    use crate::common::types::geometry::Coordinate;
+    use crate::common::types::space::Elevation;
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Sound {
+        pub name: String,
+        pub chance: u32,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Start {
+        pub elevation: Elevation,
+        pub tile: Coordinate<u8, Range<u8>>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Map {
+        pub index: u32,
+        pub lookup: String,
+        pub filename: String,
+        pub music: Option<String>,
+        pub ambient: Vec<Sound>,
+        pub saved: bool,
+        pub aging: bool,
+        pub pipboy: bool,
+        pub resting: Vec<bool>,
+        pub starts: Vec<Start>,
+    }
+}
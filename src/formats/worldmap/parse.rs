@@ -0,0 +1,356 @@
+use std::collections::HashMap;
+use std::io::{Read, Seek};
+use std::ops::RangeInclusive;
+
+use crate::common::traits::TryFromOptional;
+use crate::common::types::errors::Error;
+use crate::common::types::geometry::Coordinate;
+use crate::common::types::models::Identifier;
+use crate::common::types::space::Elevation;
+
+use super::*;
+
+mod ini;
+
+pub fn worldmap<S: Read + Seek>(source: &mut S) -> Result<Worldmap, Error> {
+    let mut worldmap = Worldmap {
+        columns: 0,
+        terrains: HashMap::new(),
+        frequencies: HashMap::new(),
+        tiles: Vec::new(),
+        encounters: Vec::new(),
+        groups: Vec::new(),
+        random: Vec::new(),
+    };
+
+    for section in ini::sections(source)? {
+        if section.name.eq_ignore_ascii_case("Data") {
+            for (key, value) in &section.entries {
+                if key.eq_ignore_ascii_case("terrain_types") {
+                    for terrain in ini::list(value) {
+                        let (name, difficulty) = terrain.split_once(':').ok_or(Error::Format)?;
+                        worldmap.terrains.insert(String::from(name.trim()), ini::number(difficulty)?);
+                    }
+                } else if let Ok(frequency) = frequency(key) {
+                    let percents = value.trim().trim_end_matches('%');
+                    worldmap.frequencies.insert(frequency, ini::number(percents)?);
+                }
+            }
+        } else if section.name.eq_ignore_ascii_case("Tile Data") {
+            worldmap.columns = ini::number(section.get("num_horizontal_tiles").ok_or(Error::Format)?)?;
+        } else if let Some(index) = section.index("Tile ") {
+            let mut subtiles = Vec::new();
+
+            for (key, value) in &section.entries {
+                let (x, y) = match key.split_once('_').map(|(x, y)| (x.parse(), y.parse())) {
+                    Some((Ok(x), Ok(y))) => (x, y),
+                    _ => continue,
+                };
+
+                if x >= tile::COLUMNS || y >= tile::ROWS { return Err(Error::Format); }
+
+                let values = ini::list(value);
+                if values.len() != 6 { return Err(Error::Format); }
+
+                subtiles.push(tile::Subtile {
+                    x,
+                    y,
+                    terrain: String::from(values[0]),
+                    fill: String::from(values[1]),
+                    chances: [frequency(values[2])?, frequency(values[3])?, frequency(values[4])?],
+                    table: String::from(values[5]),
+                });
+            }
+
+            worldmap.tiles.push(tile::Instance {
+                index,
+                art: ini::number(section.get("art_idx").ok_or(Error::Format)?)?,
+                difficulty: section.get("encounter_difficulty").map_or(Ok(0), ini::number)?,
+                walkmask: section.get("walk_mask_name").map(String::from),
+                subtiles,
+            });
+        } else if let Some(index) = section.index("Encounter Table ") {
+            worldmap.encounters.push(encounter::Table {
+                index,
+                lookup: String::from(section.get("lookup_name").ok_or(Error::Format)?),
+                maps: section.get("maps").map_or(Vec::new(), |m| ini::list(m).into_iter().map(String::from).collect()),
+                entries: section.entries.iter()
+                    .filter(|(k, _)| k.to_ascii_lowercase().starts_with("enc_"))
+                    .map(|(_, v)| entry(v))
+                    .collect::<Result<_, _>>()?,
+            });
+        } else if let Some(name) = named(&section.name, "Encounter") {
+            worldmap.groups.push(encounter::Group {
+                name: String::from(name),
+                position: section.get("position").map(String::from),
+                members: section.entries.iter()
+                    .filter(|(k, _)| k.to_ascii_lowercase().starts_with("type_"))
+                    .map(|(_, v)| member(v))
+                    .collect::<Result<_, _>>()?,
+            });
+        } else if let Some(terrain) = named(&section.name, "Random Maps") {
+            worldmap.random.push(encounter::Random {
+                terrain: String::from(terrain),
+                maps: section.entries.iter()
+                    .filter(|(k, _)| k.to_ascii_lowercase().starts_with("map_"))
+                    .map(|(_, v)| v.clone())
+                    .collect(),
+            });
+        }
+    }
+
+    Ok(worldmap)
+}
+
+pub fn cities<S: Read + Seek>(source: &mut S) -> Result<Vec<city::Area>, Error> {
+    let mut areas = Vec::new();
+
+    for section in ini::sections(source)? {
+        let index = match section.index("Area ") {
+            Some(index) => index,
+            None => continue,
+        };
+
+        let mut entrances = Vec::new();
+        for (key, value) in &section.entries {
+            if !key.to_ascii_lowercase().starts_with("entrance_") { continue; }
+
+            let values = ini::list(value);
+            if values.len() != 7 { return Err(Error::Format); }
+
+            entrances.push(city::Entrance {
+                visible: ini::flag(values[0])?,
+                position: (ini::number(values[1])?, ini::number(values[2])?),
+                map: String::from(values[3]),
+                elevation: u32::try_from_optional(ini::number(values[4])?, -1).map_err(|_| Error::Format)?,
+                tile: Coordinate::try_from_optional(ini::number(values[5])?, -1)?,
+                orientation: ini::number(values[6])?,
+            });
+        }
+
+        let position = ini::list(section.get("world_pos").ok_or(Error::Format)?);
+        if position.len() != 2 { return Err(Error::Format); }
+
+        areas.push(city::Area {
+            index,
+            name: String::from(section.get("area_name").ok_or(Error::Format)?),
+            position: (ini::number(position[0])?, ini::number(position[1])?),
+            visible: section.get("start_state").map_or(Ok(false), ini::flag)?,
+            locked: section.get("lock_state").map_or(Ok(false), ini::flag)?,
+            size: match section.get("size").unwrap_or("Small").to_ascii_lowercase().as_str() {
+                "small" => city::Size::Small,
+                "medium" => city::Size::Medium,
+                "large" => city::Size::Large,
+                _ => return Err(Error::Format)
+            },
+            townmap: section.get("townmap_art_idx").map_or(Ok(None), optional)?,
+            label: section.get("townmap_label_art_idx").map_or(Ok(None), optional)?,
+            entrances,
+        });
+    }
+
+    Ok(areas)
+}
+
+pub fn maps<S: Read + Seek>(source: &mut S) -> Result<Vec<maps::Map>, Error> {
+    let mut maps = Vec::new();
+
+    for section in ini::sections(source)? {
+        let index = match section.index("Map ") {
+            Some(index) => index,
+            None => continue,
+        };
+
+        let mut ambient = Vec::new();
+        for sound in section.get("ambient_sfx").map_or(Vec::new(), ini::list) {
+            let (name, chance) = sound.split_once(':').ok_or(Error::Format)?;
+            ambient.push(maps::Sound { name: String::from(name.trim()), chance: ini::number(chance)? });
+        }
+
+        let mut starts = Vec::new();
+        for (key, value) in &section.entries {
+            if !key.to_ascii_lowercase().starts_with("random_start_point_") { continue; }
+
+            let (mut elevation, mut tile) = (None, None);
+            for pair in ini::list(value) {
+                match pair.split_once(':').map(|(k, v)| (k.trim().to_ascii_lowercase(), v)) {
+                    Some((k, v)) if k == "elev" => elevation = Some(Elevation::try_from(ini::number::<u32>(v)?)?),
+                    Some((k, v)) if k == "tile_num" => tile = Some(Coordinate::try_from(ini::number::<u32>(v)?)?),
+                    _ => return Err(Error::Format)
+                }
+            }
+
+            starts.push(maps::Start {
+                elevation: elevation.ok_or(Error::Format)?,
+                tile: tile.ok_or(Error::Format)?,
+            });
+        }
+
+        maps.push(maps::Map {
+            index,
+            lookup: String::from(section.get("lookup_name").ok_or(Error::Format)?),
+            filename: String::from(section.get("map_name").ok_or(Error::Format)?),
+            music: section.get("music").map(String::from),
+            ambient,
+            saved: section.get("saved").map_or(Ok(true), ini::flag)?,
+            aging: section.get("dead_bodies_age").map_or(Ok(true), ini::flag)?,
+            pipboy: section.get("pipboy_active").map_or(Ok(true), ini::flag)?,
+            resting: section.get("can_rest_here").map_or(Ok(Vec::new()), |v| {
+                ini::list(v).into_iter().map(ini::flag).collect()
+            })?,
+            starts,
+        });
+    }
+
+    Ok(maps)
+}
+
+fn frequency(value: &str) -> Result<encounter::Frequency, Error> {
+    Ok(match value.trim().to_ascii_lowercase().as_str() {
+        "none" => encounter::Frequency::None,
+        "rare" => encounter::Frequency::Rare,
+        "uncommon" => encounter::Frequency::Uncommon,
+        "common" => encounter::Frequency::Common,
+        "frequent" => encounter::Frequency::Frequent,
+        "forced" => encounter::Frequency::Forced,
+        _ => return Err(Error::Format)
+    })
+}
+
+// Name of a `[Kind: Name]` section.
+fn named<'a>(section: &'a str, kind: &str) -> Option<&'a str> {
+    let (prefix, name) = section.split_once(':')?;
+    if prefix.trim().eq_ignore_ascii_case(kind) { Some(name.trim()) } else { None }
+}
+
+fn condition(part: &str) -> bool {
+    part.get(..2).is_some_and(|p| p.eq_ignore_ascii_case("if")) && part[2..].trim_start().starts_with('(')
+}
+
+fn percents(value: &str) -> Result<u32, Error> {
+    ini::number(value.trim().trim_end_matches('%'))
+}
+
+// `(min-max)` or `(count)` prefix of a token.
+fn count(token: &str) -> Result<(RangeInclusive<u32>, &str), Error> {
+    let token = token.trim();
+    let (count, rest) = match token.strip_prefix('(') {
+        Some(token) => token.split_once(')').ok_or(Error::Format)?,
+        None => return Ok((1..=1, token)),
+    };
+
+    let range = match count.split_once('-') {
+        Some((min, max)) => ini::number(min)?..=ini::number(max)?,
+        None => { let count = ini::number(count)?; count..=count }
+    };
+
+    Ok((range, rest))
+}
+
+// `chance:20%, counter:3, special, map:NAME, enc:(2-4) GROUP AND (1) GROUP, If(...)` lines of tables.
+fn entry(value: &str) -> Result<encounter::Entry, Error> {
+    let mut entry = encounter::Entry {
+        chance: 0,
+        counter: None,
+        special: false,
+        map: None,
+        spawns: Vec::new(),
+        conditions: Vec::new(),
+    };
+
+    for part in ini::list(value) {
+        if condition(part) { entry.conditions.push(String::from(part)); continue; }
+        if part.eq_ignore_ascii_case("special") { entry.special = true; continue; }
+
+        let (key, value) = match part.split_once(':') {
+            Some(pair) => pair,
+            None => { entry.conditions.push(String::from(part)); continue; }
+        };
+
+        match key.trim().to_ascii_lowercase().as_str() {
+            "chance" => entry.chance = percents(value)?,
+            "counter" => entry.counter = Some(ini::number(value)?),
+            "map" => entry.map = Some(String::from(value.trim())),
+            "enc" => {
+                let mut relation = None;
+                let mut amount = None;
+                let mut tokens = value.split_whitespace();
+
+                while let Some(token) = tokens.next() {
+                    match token.to_ascii_lowercase().as_str() {
+                        "and" => relation = Some(encounter::Relation::And),
+                        "fighting" => relation = Some(encounter::Relation::Fighting),
+                        "ambush" => {
+                            let spawn = entry.spawns.last_mut().ok_or(Error::Format)?;
+                            spawn.ambush = Some(String::from(tokens.next().ok_or(Error::Format)?));
+                        }
+                        _ if token.starts_with('(') => amount = Some(count(token)?.0),
+                        _ => entry.spawns.push(encounter::Spawn {
+                            count: amount.take().unwrap_or(1..=1),
+                            group: String::from(token),
+                            relation: relation.take(),
+                            ambush: None,
+                        }),
+                    }
+                }
+            }
+            _ => entry.conditions.push(String::from(part)),
+        }
+    }
+
+    Ok(entry)
+}
+
+// `ratio:50%, pid:16777244, Item:(0-10)41{Wielded}, Script:12, Dead, If(...)` lines of groups.
+fn member(value: &str) -> Result<encounter::group::Member, Error> {
+    let mut member = encounter::group::Member {
+        ratio: None,
+        prototype: None,
+        dead: false,
+        items: Vec::new(),
+        script: None,
+        conditions: Vec::new(),
+    };
+
+    for part in ini::list(value) {
+        if condition(part) { member.conditions.push(String::from(part)); continue; }
+        if part.eq_ignore_ascii_case("dead") { member.dead = true; continue; }
+
+        let (key, value) = match part.split_once(':') {
+            Some(pair) => pair,
+            None => { member.conditions.push(String::from(part)); continue; }
+        };
+
+        match key.trim().to_ascii_lowercase().as_str() {
+            "ratio" => member.ratio = Some(percents(value)?),
+            "pid" => member.prototype = Some(Identifier::try_from(ini::number::<u32>(value)?)?),
+            "script" => member.script = Some(ini::number(value)?),
+            "item" => {
+                let (count, rest) = count(value)?;
+                let (prototype, wielded) = match rest.split_once('{') {
+                    Some((prototype, flag)) => {
+                        if !flag.trim_end_matches('}').trim().eq_ignore_ascii_case("wielded") {
+                            return Err(Error::Format);
+                        }
+                        (prototype, true)
+                    }
+                    None => (rest, false),
+                };
+
+                member.items.push(encounter::group::Item {
+                    count,
+                    prototype: Identifier::try_from(ini::number::<u32>(prototype)?)?,
+                    wielded,
+                });
+            }
+            _ => member.conditions.push(String::from(part)),
+        }
+    }
+
+    Ok(member)
+}
+
+fn optional(value: &str) -> Result<Option<u32>, Error> {
+    let value: i32 = ini::number(value)?;
+    Ok(u32::try_from(value).ok())
+}
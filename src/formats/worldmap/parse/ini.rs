@@ -0,0 +1,67 @@
+use std::io::{Read, Seek, SeekFrom};
+
+use crate::common::types::errors::Error;
+
+pub(crate) struct Section {
+    pub(crate) name: String,
+    pub(crate) entries: Vec<(String, String)>,
+}
+
+impl Section {
+    pub(crate) fn get(&self, key: &str) -> Option<&str> {
+        self.entries.iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(key))
+            .map(|(_, v)| v.as_str())
+    }
+
+    pub(crate) fn index(&self, prefix: &str) -> Option<u32> {
+        self.name.strip_prefix(prefix).and_then(|i| i.trim().parse().ok())
+    }
+}
+
+pub(crate) fn sections<S: Read + Seek>(source: &mut S) -> Result<Vec<Section>, Error> {
+    source.seek(SeekFrom::Start(0))?;
+
+    let mut bytes = Vec::new();
+    source.read_to_end(&mut bytes)?;
+
+    let text = std::str::from_utf8(&bytes).map_err(|_| Error::Format)?;
+    let mut sections: Vec<Section> = Vec::new();
+
+    for line in text.lines() {
+        let line = line.split(';').next().unwrap_or_default().trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with("//") { continue; }
+
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            sections.push(Section { name: String::from(name.trim()), entries: Vec::new() });
+            continue;
+        }
+
+        // Like the games do, lines which are not `key=value` pairs are ignored.
+        let (key, value) = match line.split_once('=') {
+            Some(pair) => pair,
+            None => continue,
+        };
+        let section = sections.last_mut().ok_or(Error::Format)?;
+
+        section.entries.push((String::from(key.trim()), String::from(value.trim())));
+    }
+
+    Ok(sections)
+}
+
+pub(crate) fn list(value: &str) -> Vec<&str> {
+    value.split(',').map(|v| v.trim()).filter(|v| !v.is_empty()).collect()
+}
+
+pub(crate) fn number<T: std::str::FromStr>(value: &str) -> Result<T, Error> {
+    value.trim().parse().map_err(|_| Error::Format)
+}
+
+pub(crate) fn flag(value: &str) -> Result<bool, Error> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "yes" | "on" => Ok(true),
+        "no" | "off" => Ok(false),
+        _ => Err(Error::Format)
+    }
+}
@@ -1,2 +1,4 @@
 pub mod common;
 pub mod formats;
+pub mod provider;
+pub mod render;
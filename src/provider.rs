@@ -0,0 +1,14 @@
+use std::sync::Arc;
+
+use crate::common::types::errors;
+use crate::common::types::models::Identifier;
+use crate::common::types::models::sprite::Kind;
+use crate::formats::{frm, pal};
+
+pub mod storage;
+pub mod sprite;
+pub mod prototype;
+
+pub trait SpriteProvider {
+    fn provide(&self, identifier: &Identifier<Kind>) -> Result<(Arc<frm::Sprite>, Option<Arc<pal::Palette>>), errors::Error>;
+}
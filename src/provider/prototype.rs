@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+use std::io::{BufRead, Cursor};
+use std::sync::{Arc, Mutex};
+
+use crate::common::types::errors::Error;
+use crate::common::types::models::Identifier;
+use crate::common::types::version::Version;
+use crate::formats::{map, pro};
+use crate::formats::pro::{ObjectType, Prototype};
+use crate::formats::pro::database::Database;
+
+use super::storage::Storage;
+
+// Prototypes which couldn't be read, with their errors.
+pub type Failures = Vec<(Identifier<ObjectType>, Error)>;
+
+// Every prototype is read once and then shared between all the objects referring to it.
+pub struct CachingProvider<S: Storage> {
+    pub storage: S,
+    pub version: Version,
+    lists: Mutex<HashMap<&'static str, Arc<Vec<String>>>>,
+    cache: Mutex<HashMap<Identifier<ObjectType>, Arc<Prototype>>>,
+}
+
+impl<S: Storage> CachingProvider<S> {
+    pub fn new(storage: S, version: Version) -> Self {
+        Self { storage, version, lists: Mutex::new(HashMap::new()), cache: Mutex::new(HashMap::new()) }
+    }
+
+    fn list(&self, kind: &'static str) -> Result<Arc<Vec<String>>, Error> {
+        if let Some(list) = self.lists.lock().unwrap_or_else(|e| e.into_inner()).get(kind) { return Ok(list.clone()); }
+
+        let bytes = self.storage.read(&format!("PROTO/{}/{}.LST", kind, kind))?;
+        let list = Arc::new(Cursor::new(bytes).lines()
+            .map(|line| line.map(|line| line.trim().to_string()))
+            .collect::<Result<Vec<String>, _>>()?);
+
+        self.lists.lock().unwrap_or_else(|e| e.into_inner()).insert(kind, list.clone());
+        Ok(list)
+    }
+
+    fn read(&self, kind: &str, name: &str) -> Result<Prototype, Error> {
+        let bytes = self.storage.read(&format!("PROTO/{}/{}", kind, name))?;
+        pro::parse::prototype(&mut Cursor::new(bytes), &self.version, &pro::parse::Mode::Lenient)
+    }
+
+    // Reads every prototype listed in `.LST` files, leaving out the ones which couldn't be read.
+    pub fn database(&self) -> Result<(Database, Failures), Error> {
+        let mut entries = Vec::new();
+        let mut failures = Vec::new();
+
+        for kind in [
+            ObjectType::Item(()),
+            ObjectType::Critter(()),
+            ObjectType::Scenery(()),
+            ObjectType::Wall(()),
+            ObjectType::Tile(()),
+            ObjectType::Misc(()),
+        ] {
+            let directory = directory(&kind);
+
+            // indexes in .LST files start from 1
+            for (line, name) in self.list(directory)?.iter().enumerate().take(u16::MAX as usize) {
+                if name.is_empty() { continue; }
+
+                let identifier = Identifier { index: line as u16 + 1, kind: kind.clone() };
+                match self.read(directory, name) {
+                    Ok(prototype) => entries.push((identifier, prototype)),
+                    Err(error) => failures.push((identifier, error)),
+                }
+            }
+        }
+
+        Ok((Database::new(entries), failures))
+    }
+}
+
+fn directory(kind: &ObjectType) -> &'static str {
+    match kind {
+        ObjectType::Item(_) => "ITEMS",
+        ObjectType::Critter(_) => "CRITTERS",
+        ObjectType::Scenery(_) => "SCENERY",
+        ObjectType::Wall(_) => "WALLS",
+        ObjectType::Tile(_) => "TILES",
+        ObjectType::Misc(_) => "MISC",
+    }
+}
+
+impl<S: Storage> map::parse::Provider for CachingProvider<S> {
+    fn provide(&self, identifier: &Identifier<ObjectType>) -> Result<Arc<Prototype>, Error> {
+        if let Some(prototype) = self.cache.lock().unwrap_or_else(|e| e.into_inner()).get(identifier) { return Ok(prototype.clone()); }
+
+        let kind = directory(&identifier.kind);
+
+        // indexes in .LST files start from 1
+        let list = self.list(kind)?;
+        let name = (identifier.index as usize).checked_sub(1)
+            .and_then(|index| list.get(index))
+            .ok_or(Error::Format)?;
+
+        let prototype = Arc::new(self.read(kind, name)?);
+
+        self.cache.lock().unwrap_or_else(|e| e.into_inner()).insert(identifier.clone(), prototype.clone());
+        Ok(prototype)
+    }
+}
@@ -0,0 +1,102 @@
+use std::io::{BufRead, Cursor};
+use std::sync::Arc;
+
+use crate::common::types::errors::Error;
+use crate::common::types::geometry::Orientation;
+use crate::common::types::models::Identifier;
+use crate::common::types::models::sprite::Kind;
+use crate::formats::{frm, pal};
+use crate::formats::frm::Sprite;
+use crate::formats::pal::Palette;
+
+use super::SpriteProvider;
+use super::storage::Storage;
+
+pub struct StorageProvider<S: Storage> {
+    pub storage: S,
+}
+
+impl<S: Storage> StorageProvider<S> {
+    fn sprite(&self, path: &str) -> Result<Sprite, Error> {
+        frm::parse::sprite(&mut Cursor::new(self.storage.read(path)?))
+    }
+
+    // Critters are either in a single .frm or split by orientation into .fr0-5 files.
+    fn critter(&self, path: &str, orientation: &Option<Orientation>) -> Result<Sprite, Error> {
+        match orientation {
+            None => self.sprite(&(path.to_owned() + ".frm")),
+            Some(_) => {
+                let mut sprites = Vec::with_capacity(6);
+                for i in 0..6 { sprites.push(self.sprite(&format!("{}.fr{}", path, i))?); }
+
+                let sprites: [Sprite; 6] = sprites.try_into().map_err(|_| Error::Format)?;
+                frm::merge::sprites(sprites).map_err(|_| Error::Format)
+            }
+        }
+    }
+}
+
+impl<S: Storage> SpriteProvider for StorageProvider<S> {
+    fn provide(&self, identifier: &Identifier<Kind>) -> Result<(Arc<Sprite>, Option<Arc<Palette>>), Error> {
+        let subdirectory = match identifier.kind {
+            Kind::Item => "ITEMS",
+            Kind::Critter(_, _, _) => "CRITTERS",
+            Kind::Scenery => "SCENERY",
+            Kind::Wall => "WALLS",
+            Kind::Tile => "TILES",
+            Kind::Misc => "MISC",
+            Kind::Background => "BACKGRND",
+            Kind::Interface => "INTRFACE",
+            Kind::Inventory => "INVEN",
+            Kind::Head => "HEADS",
+            Kind::Skilldex => "SKILLDEX",
+        };
+
+        let directory = format!("ART/{}/", subdirectory);
+        let list = self.storage.read(&format!("{}{}.LST", directory, subdirectory))?;
+
+        let row = |index: u16| -> Result<Vec<String>, Error> {
+            let line = Cursor::new(&list).lines()
+                .nth(index as usize)
+                .ok_or(Error::Format)??;
+
+            let records = line.split([' ', ';', '\t']).next().unwrap_or(&line);
+            Ok(records.split(',').map(|s| s.trim().to_string()).collect())
+        };
+
+        let name = |row: &Vec<String>| -> Result<String, Error> {
+            row.first().map(|name| directory.clone() + name).ok_or(Error::Format)
+        };
+
+        let entry = row(identifier.index)?;
+        let mut path = name(&entry)?;
+
+        let sprite = if let Kind::Critter(orientation, _, _) = &identifier.kind {
+            let suffix = identifier.kind.suffix().ok_or(Error::Format)?;
+            let suffixed = |path: &str| format!("{}{}{}", path.trim_end_matches(".FRM").trim_end_matches(".frm"), suffix.0, suffix.1);
+
+            match self.critter(&suffixed(&path), orientation) {
+                Ok(sprite) => sprite,
+                Err(_) => {
+                    // the second record of a critter's entry points to the one to borrow animations from
+                    let index = entry.get(1)
+                        .and_then(|s| s.parse::<u16>().ok())
+                        .ok_or(Error::Format)?;
+
+                    path = name(&row(index)?)?;
+                    self.critter(&suffixed(&path), orientation)?
+                }
+            }
+        } else {
+            self.sprite(&path)?
+        };
+
+        let stem = path.rsplit_once('.').map_or(path.as_str(), |(stem, _)| stem);
+        let palette = match self.storage.read(&(stem.to_owned() + ".pal")) {
+            Err(_) => None,
+            Ok(bytes) => Some(Arc::new(pal::parse::palette(&mut Cursor::new(bytes))?)),
+        };
+
+        Ok((Arc::new(sprite), palette))
+    }
+}
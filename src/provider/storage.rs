@@ -0,0 +1,85 @@
+use std::io::{ErrorKind, Read, Seek};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use crate::common::types::errors::Error;
+use crate::formats::dat;
+
+// Resources are addressed the way the game does it, relative to its root, e.g. `ART/TILES/TILES.LST`,
+// with either separator and in any case.
+pub trait Storage {
+    fn read(&self, path: &str) -> Result<Vec<u8>, Error>;
+}
+
+fn components(path: &str) -> impl Iterator<Item=&str> {
+    path.split(['/', '\\']).filter(|c| !c.is_empty() && *c != ".")
+}
+
+fn missing() -> Error {
+    Error::IO(std::io::Error::from(ErrorKind::NotFound))
+}
+
+pub struct Directory {
+    pub root: PathBuf,
+}
+
+impl Storage for Directory {
+    fn read(&self, path: &str) -> Result<Vec<u8>, Error> {
+        let mut current = self.root.clone();
+
+        for component in components(path) {
+            let exact = current.join(component);
+
+            current = if exact.exists() { exact } else {
+                std::fs::read_dir(&current)?
+                    .filter_map(|entry| entry.ok())
+                    .find(|entry| entry.file_name().to_str().is_some_and(|n| n.eq_ignore_ascii_case(component)))
+                    .map(|entry| entry.path())
+                    .ok_or_else(missing)?
+            };
+        }
+
+        Ok(std::fs::read(current)?)
+    }
+}
+
+pub struct Archive<S: Read + Seek> {
+    source: Mutex<S>,
+    tree: dat::Directory,
+}
+
+impl<S: Read + Seek> Archive<S> {
+    pub fn new(mut source: S) -> Result<Self, Error> {
+        let tree = dat::parse::tree(&mut source)?.unwrap_or(dat::Directory {
+            name: String::from("."),
+            files: Vec::new(),
+            children: Vec::new(),
+        });
+
+        Ok(Self { source: Mutex::new(source), tree })
+    }
+}
+
+impl<S: Read + Seek> Storage for Archive<S> {
+    fn read(&self, path: &str) -> Result<Vec<u8>, Error> {
+        let components: Vec<&str> = components(path).collect();
+        let (name, directories) = components.split_last().ok_or_else(missing)?;
+
+        let mut directory = &self.tree;
+        for component in directories {
+            directory = directory.children.iter()
+                .find(|d| d.name.eq_ignore_ascii_case(component))
+                .ok_or_else(missing)?;
+        }
+
+        let file = directory.files.iter()
+            .find(|f| f.name.eq_ignore_ascii_case(name))
+            .ok_or_else(missing)?;
+
+        let mut output = Vec::with_capacity(file.size as usize);
+        let mut source = self.source.lock().unwrap_or_else(|e| e.into_inner());
+        dat::extract::file(&mut *source, file, &mut output)?;
+
+        Ok(output)
+    }
+}
@@ -0,0 +1,114 @@
+use crate::common::types::errors;
+use crate::common::types::geometry::hex;
+use crate::formats::{map, pal};
+use crate::provider::SpriteProvider;
+
+mod canvas;
+mod frame;
+mod grid;
+mod objects;
+pub mod order;
+mod overlay;
+mod tiles;
+
+// vanilla game tile size, it spans a step along both axes of floor / roofs grid
+const TILE_WIDTH: usize = (hex::TILE_COLUMN_STEP.0 + hex::TILE_ROW_STEP.0) as usize;
+const TILE_HEIGHT: usize = (hex::TILE_ROW_STEP.1 - hex::TILE_COLUMN_STEP.1) as usize;
+const SIDE_LEN: usize = hex::TILE_SIDE_LEN as usize;
+
+pub const WIDTH: usize = TILE_WIDTH * SIDE_LEN;
+pub const HEIGHT: usize = TILE_HEIGHT * SIDE_LEN;
+
+pub const ROOF_OFFSET: isize = 96;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layers {
+    pub floor: bool,
+    pub roof: bool,
+    pub walls: bool,
+    pub scenery: bool,
+    pub items: bool,
+    pub critters: bool,
+    pub misc: bool,
+    pub overlay: bool,
+}
+
+impl Default for Layers {
+    fn default() -> Self {
+        Self {
+            floor: true,
+            roof: true,
+            walls: true,
+            scenery: true,
+            items: true,
+            critters: true,
+            misc: true,
+            overlay: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Options {
+    pub layers: Layers,
+    // from 1 (the darkest) to 4, map's own value is used if absent
+    pub darkness: Option<u8>,
+    pub region: Option<Region>,
+}
+
+// RGBA, 4 bytes per pixel, rows top to bottom
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<u8>,
+}
+
+pub fn map<P: SpriteProvider>(map: &map::Map, provider: &P, palette: &pal::Palette, options: &Options) ->
+Result<Vec<(u8, Image)>, errors::Error> {
+    map.tiles.iter().map(|group| {
+        let level = group.elevation.level.value;
+        elevation(map, level, provider, palette, options).map(|image| (level, image))
+    }).collect()
+}
+
+pub fn elevation<P: SpriteProvider>(map: &map::Map, level: u8, provider: &P, palette: &pal::Palette, options: &Options) ->
+Result<Image, errors::Error> {
+    let group = map.tiles.iter()
+        .find(|group| group.elevation.level.value == level)
+        .ok_or(errors::Error::Format)?;
+
+    let darkness = options.darkness.unwrap_or(u8::try_from(map.darkness).map_err(|_| errors::Error::Format)?);
+    let region = options.region.clone().unwrap_or(Region { x: 0, y: 0, width: WIDTH, height: HEIGHT });
+
+    let mut canvas = canvas::Canvas::new(&region);
+    let layers = &options.layers;
+
+    if layers.floor {
+        tiles::imprint(&group.floor, 0, provider, palette, darkness, &mut canvas)?;
+    }
+
+    if layers.overlay {
+        overlay::imprint(&mut canvas);
+    }
+
+    let objects: Vec<_> = map.prototypes.iter()
+        .filter(|p| p.location.grid.as_ref().is_some_and(|g| g.elevation == group.elevation))
+        .collect();
+
+    objects::imprint(&objects, provider, palette, darkness, layers, &mut canvas)?;
+
+    if layers.roof {
+        tiles::imprint(&group.ceiling, ROOF_OFFSET, provider, palette, darkness, &mut canvas)?;
+    }
+
+    Ok(canvas.image)
+}
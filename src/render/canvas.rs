@@ -0,0 +1,37 @@
+use super::{Image, Region};
+
+pub(crate) struct Canvas {
+    pub(crate) image: Image,
+    origin: (isize, isize),
+}
+
+impl Canvas {
+    pub(crate) fn new(region: &Region) -> Self {
+        Self {
+            image: Image { width: region.width, height: region.height, pixels: vec![0; region.width * region.height * 4] },
+            origin: (region.x as isize, region.y as isize),
+        }
+    }
+
+    // Points are in the whole map's coordinates, anything outside of the region is dropped.
+    fn index(&self, x: isize, y: isize) -> Option<usize> {
+        let (x, y) = (x - self.origin.0, y - self.origin.1);
+
+        if x < 0 || y < 0 || x >= self.image.width as isize || y >= self.image.height as isize { return None; }
+        Some((x as usize + y as usize * self.image.width) * 4)
+    }
+
+    pub(crate) fn put(&mut self, x: isize, y: isize, color: (u8, u8, u8)) {
+        if let Some(index) = self.index(x, y) {
+            self.image.pixels[index..index + 4].copy_from_slice(&[color.0, color.1, color.2, u8::MAX]);
+        }
+    }
+
+    pub(crate) fn reddify(&mut self, x: isize, y: isize) {
+        if let Some(index) = self.index(x, y) {
+            let red = &mut self.image.pixels[index];
+            *red = red.saturating_add(u8::MAX / 4);
+            self.image.pixels[index + 3] = u8::MAX;
+        }
+    }
+}
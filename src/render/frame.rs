@@ -0,0 +1,48 @@
+use std::ops::RangeInclusive;
+
+use crate::common::types::errors;
+use crate::common::types::geometry::{Orientation, Scaled};
+use crate::formats::frm::{Frame, FrameIndex, Shift, Sprite};
+use crate::formats::pal::Palette;
+
+use super::canvas::Canvas;
+
+pub(crate) fn select<'a>(sprite: &'a Sprite, orientation: &Orientation, index: Option<FrameIndex>) ->
+Result<(&'a Frame, &'a Shift), errors::Error> {
+    let orientation_idx = orientation.scaled.value as usize * 6 / orientation.scaled.scale.len();
+    let animation_idx = sprite.orientations[orientation_idx];
+
+    let animation = sprite.animations
+        .get(animation_idx as usize)
+        .ok_or(errors::Error::Format)?;
+
+    let frame_idx = index.unwrap_or(sprite.keyframe);
+
+    animation.frames.get(frame_idx as usize)
+        .ok_or(errors::Error::Format)
+        .map(|f| (f, &animation.shift))
+}
+
+pub(crate) fn imprint(frame: &Frame, palette: &Palette, darkness: u8, origin: (isize, isize), canvas: &mut Canvas) {
+    let origin = (origin.0 + frame.shift.x as isize, origin.1 + frame.shift.y as isize);
+
+    fn adjusted(color: &Scaled<u8, RangeInclusive<u8>>, darkness: u8) -> u8 {
+        let max = ((0..u8::MAX).len() / color.scale.len() + 1) as u8;
+        color.value * darkness.clamp(1, max)
+    }
+
+    for (number, &index) in frame.indexes.iter().enumerate() {
+        if let Some(pixel) = &palette.colors[index as usize] {
+            let (rx, ry) = (
+                number as isize % frame.size.width as isize,
+                number as isize / frame.size.width as isize
+            );
+
+            canvas.put(
+                origin.0 + rx,
+                origin.1 + ry,
+                (adjusted(&pixel.red, darkness), adjusted(&pixel.green, darkness), adjusted(&pixel.blue, darkness)),
+            );
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::common::types::geometry::{hex, Coordinate, Scaled};
+
+use super::SIDE_LEN;
+
+// Hexes and tiles are placed relative to the first hex, images start at the top left corner of the
+// floor grid instead, which is its western and northern tiles' edges.
+fn origin() -> (isize, isize) {
+    let corner = |column: usize, row: usize| hex::tile_screen(&Coordinate {
+        x: Scaled { value: column as u8, scale: u8::MIN..SIDE_LEN as u8 },
+        y: Scaled { value: row as u8, scale: u8::MIN..SIDE_LEN as u8 },
+    });
+
+    (-corner(0, 0).0 as isize, -corner(SIDE_LEN - 1, 0).1 as isize)
+}
+
+// Top left corner of a floor tile.
+pub(crate) fn tile(tile: &hex::Tile) -> (isize, isize) {
+    let ((x, y), (ox, oy)) = (hex::tile_screen(tile), origin());
+    (x as isize + ox, y as isize + oy)
+}
+
+// Top left corner of a hex, objects stand at its center.
+pub(crate) fn hex(hex: &hex::Hex) -> (isize, isize) {
+    let ((x, y), (ox, oy)) = (hex::screen(hex), origin());
+    (x as isize + ox, y as isize + oy)
+}
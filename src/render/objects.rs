@@ -0,0 +1,63 @@
+use crate::common::types::errors;
+use crate::common::types::geometry::hex;
+use crate::formats::map::blueprint::prototype;
+use crate::formats::pal::Palette;
+use crate::formats::pro;
+use crate::formats::pro::Type::{Critter, Item, Misc, Scenery, Tile, Wall};
+use crate::provider::SpriteProvider;
+
+use super::{frame, grid, order, Layers};
+use super::canvas::Canvas;
+
+pub(crate) fn imprint<P: SpriteProvider>(
+    objects: &[&prototype::Instance],
+    provider: &P,
+    palette: &Palette,
+    darkness: u8,
+    layers: &Layers,
+    canvas: &mut Canvas,
+) -> Result<(), errors::Error> {
+    fn visible<I, C, S, W, T, M>(t: &pro::Type<I, C, S, W, T, M>, layers: &Layers) -> bool {
+        match t {
+            Item(_) => layers.items,
+            Critter(_) => layers.critters,
+            Scenery(_) => layers.scenery,
+            Wall(_) => layers.walls,
+            Tile(_) => true,
+            Misc(_) => layers.misc,
+        }
+    }
+
+    let mut objects = objects.to_vec();
+    order::sort(&mut objects);
+
+    for object in objects.iter().filter(|o| visible(&o.id.kind, layers)) {
+        let location = match &object.location.grid {
+            None => continue,
+            Some(location) => location,
+        };
+
+        let correction = &object.location.screen.correction;
+        let (sprite, custom) = provider.provide(&object.appearance.sprite)?;
+        let palette = custom.as_deref().unwrap_or(palette);
+
+        let (frame, shift) = frame::select(&sprite, &location.orientation, object.appearance.current)?;
+        let point = grid::hex(&location.position);
+        let point = (point.0 + hex::WIDTH as isize / 2, point.1 + hex::HEIGHT as isize / 2);
+
+        // Aligning with frame's shift within it's own bounds.
+        let (x, y) = (
+            point.0 - (frame.size.width as i16 + frame.shift.x) as isize / 2,
+            point.1 - (frame.size.height as i16 + frame.shift.y) as isize
+        );
+
+        let (x, y) = (
+            x + correction.x.value as isize + shift.x as isize,
+            y + correction.y.value as isize + shift.y as isize
+        );
+
+        frame::imprint(frame, palette, darkness, (x, y), canvas);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use crate::common::types::geometry::hex;
+use crate::formats::map::blueprint::prototype;
+use crate::formats::map::path;
+use crate::formats::pro::meta::info::flags::Root;
+use crate::formats::pro::Type;
+
+// Draw order of the engine: flat objects go first, then hex by hex in screen order, top to bottom
+// and left to right. Multi-hex objects go at the lowest row of their footprint, which their art
+// covers, so objects standing on their upper hexes don't overlap them. Within a single hex walls and
+// scenery, which block it, are drawn below anything that stands on it.
+//
+// The transparency egg around the player is out of scope: the engine draws some walls and scenery
+// through it depending on their prototypes' light flags and where they stand relative to the egg,
+// and that rule is yet to be checked against the engine, so everything is drawn opaque for now.
+pub fn key(instance: &prototype::Instance) -> (bool, i32, i32, u8) {
+    let flat = instance.patch.meta.flags.contains(&Root::Flat);
+
+    let (x, y) = match &instance.location.grid {
+        None => (i32::MAX, i32::MAX),
+        Some(grid) => {
+            let (x, _) = hex::center(&grid.position);
+            let y = path::footprint(instance).iter().map(|h| hex::center(h).1).max().unwrap_or(i32::MAX);
+
+            (x, y)
+        }
+    };
+
+    let weight = match instance.id.kind {
+        Type::Tile(_) => 0,
+        Type::Wall(_) => 1,
+        Type::Scenery(_) => 2,
+        Type::Misc(_) => 3,
+        Type::Item(_) => 4,
+        Type::Critter(_) => 5,
+    };
+
+    (!flat, y, x, weight)
+}
+
+// Objects without a location on the grid end up last.
+pub fn sort(objects: &mut [&prototype::Instance]) {
+    objects.sort_by_key(|o| key(o));
+}
@@ -0,0 +1,62 @@
+use crate::common::types::geometry::hex;
+
+use super::canvas::Canvas;
+use super::grid;
+
+pub(crate) fn imprint(canvas: &mut Canvas) {
+    let (tw, th) = (hex::WIDTH as isize, hex::HEIGHT as isize);
+    let sh = 2 * (hex::HEIGHT - hex::STEP) as isize;
+
+    for number in 0..(hex::SIDE_LEN * hex::SIDE_LEN) as u32 {
+        let (ox, oy) = match hex::Hex::try_from(number) {
+            Ok(position) => grid::hex(&position),
+            Err(_) => continue,
+        };
+
+        let v0 = (ox + tw / 2, oy);
+        let v1 = (ox + tw - 1, oy + (th - sh) / 2 - 1);
+        let v2 = (ox + tw - 1, oy + th - sh / 2 - 1);
+        let v3 = (ox + tw / 2, oy + th - 1);
+        let v4 = (ox, oy + th - sh / 2 - 1);
+        let v5 = (ox, oy + (th - sh) / 2 - 1);
+
+        for (start, end) in [(v0, v1), (v1, v2), (v2, v3), (v3, v4), (v4, v5), (v5, v0)] {
+            line(start, end, canvas);
+        }
+    }
+}
+
+// Bresenham's line algorithm
+// https://en.wikipedia.org/wiki/Bresenham%27s_line_algorithm
+fn line(start: (isize, isize), end: (isize, isize), canvas: &mut Canvas) {
+    let (mut x, mut y) = start;
+    let (ex, ey) = end;
+
+    let dx = (ex - x).abs();
+    let sx = if x < ex { 1 } else { -1 };
+
+    let dy = -(ey - y).abs();
+    let sy = if y < ey { 1 } else { -1 };
+
+    let mut error = dx + dy;
+
+    loop {
+        canvas.reddify(x, y);
+        if x == ex && y == ey { break; }
+
+        let error_doubled = 2 * error;
+        if error_doubled >= dy {
+            if x == ex { break; }
+
+            error += dy;
+            x += sx;
+        }
+
+        if error_doubled <= dx {
+            if y == ey { break; }
+
+            error += dx;
+            y += sy;
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use crate::common::types::errors;
+use crate::common::types::geometry::{Orientation, Scaled};
+use crate::common::types::models::Identifier;
+use crate::common::types::models::sprite::Kind;
+use crate::formats::map;
+use crate::formats::pal::Palette;
+use crate::provider::SpriteProvider;
+
+use super::{frame, grid};
+use super::canvas::Canvas;
+
+pub(crate) fn imprint<P: SpriteProvider>(
+    tiles: &[map::tiles::Instance<u8, u8>],
+    offset: isize,
+    provider: &P,
+    palette: &Palette,
+    darkness: u8,
+    canvas: &mut Canvas,
+) -> Result<(), errors::Error> {
+    for tile in tiles {
+        let identifier = Identifier { kind: Kind::Tile, index: tile.id & 0b1111_1111_1111 };
+        let (sprite, custom) = provider.provide(&identifier)?;
+
+        let palette = custom.as_deref().unwrap_or(palette);
+        let (frame, shift) = frame::select(
+            &sprite, &Orientation { scaled: Scaled { value: 0, scale: 0..6 } }, None,
+        )?;
+
+        let (x, y) = grid::tile(&tile.position);
+        let (x, y) = (x + shift.x as isize, y - offset + shift.y as isize);
+
+        frame::imprint(frame, palette, darkness, (x, y), canvas);
+    }
+
+    Ok(())
+}